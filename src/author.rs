@@ -0,0 +1,103 @@
+use crate::Result;
+#[cfg(not(feature = "json"))]
+use crate::{AUTHORS, Error};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// Author
+///
+/// Authors are stored in the `AUTHORS` value as a JSON array of objects. A
+/// list of plain names that contain no commas is stored in the legacy comma
+/// separated form, which is read as well. Without the `json` feature only the
+/// legacy form is read and written, so only the names are kept.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Author {
+    /// Display name
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub given: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orcid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub affiliation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
+impl Author {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Parses an `AUTHORS` value, keeping empty legacy entries
+    pub fn from_value(value: &str) -> Result<Vec<Self>> {
+        if value.trim_start().starts_with('[') {
+            #[cfg(feature = "json")]
+            return Ok(serde_json::from_str(value)?);
+            #[cfg(not(feature = "json"))]
+            return Err(Error::Format {
+                key: AUTHORS.to_owned(),
+                value: value.to_owned(),
+            });
+        }
+        Ok(value.split(',').map(Self::from).collect())
+    }
+
+    /// Serializes authors into an `AUTHORS` value
+    pub fn to_value(authors: &[Self]) -> String {
+        #[cfg(feature = "json")]
+        if !authors.iter().all(Self::is_plain) {
+            // Serializing strings into a JSON array can not fail
+            return serde_json::to_string(authors).unwrap_or_default();
+        }
+        authors
+            .iter()
+            .map(|author| &*author.name)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Only a name, which can be written in the legacy form
+    #[cfg(feature = "json")]
+    fn is_plain(&self) -> bool {
+        let Self {
+            name,
+            given,
+            family,
+            email,
+            orcid,
+            affiliation,
+            role,
+        } = self;
+        !name.contains(',')
+            && !name.trim_start().starts_with('[')
+            && [given, family, email, orcid, affiliation, role]
+                .iter()
+                .all(|field| field.is_none())
+    }
+}
+
+impl From<&str> for Author {
+    fn from(value: &str) -> Self {
+        Self::new(value.trim())
+    }
+}
+
+impl From<String> for Author {
+    fn from(value: String) -> Self {
+        Self::from(&*value)
+    }
+}
+
+impl Display for Author {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
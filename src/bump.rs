@@ -0,0 +1,55 @@
+use semver::{BuildMetadata, Prerelease, Version};
+use serde::{Deserialize, Serialize};
+
+/// Bump
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Bump {
+    Major,
+    Minor,
+    Patch,
+}
+
+impl Bump {
+    /// Increments the component and resets the lower ones, the pre-release
+    /// and the build metadata
+    ///
+    /// As with Cargo and npm, a pre-release whose lower components are
+    /// already zero is released instead, so a patch bump of `1.2.3-rc.1`
+    /// gives `1.2.3` and a minor bump of `1.3.0-rc.1` gives `1.3.0`.
+    pub fn apply(self, version: &Version) -> Version {
+        let Version {
+            major,
+            minor,
+            patch,
+            ..
+        } = *version;
+        let release = !version.pre.is_empty();
+        let (major, minor, patch) = match self {
+            Self::Major if release && minor == 0 && patch == 0 => (major, 0, 0),
+            Self::Major => (major + 1, 0, 0),
+            Self::Minor if release && patch == 0 => (major, minor, 0),
+            Self::Minor => (major, minor + 1, 0),
+            Self::Patch if release => (major, minor, patch),
+            Self::Patch => (major, minor, patch + 1),
+        };
+        Version {
+            major,
+            minor,
+            patch,
+            pre: Prerelease::EMPTY,
+            build: BuildMetadata::EMPTY,
+        }
+    }
+
+    /// Highest component increased from the old version to the new one, if
+    /// the new version is higher
+    pub fn between(old: &Version, new: &Version) -> Option<Self> {
+        if new.major != old.major {
+            (new.major > old.major).then_some(Self::Major)
+        } else if new.minor != old.minor {
+            (new.minor > old.minor).then_some(Self::Minor)
+        } else {
+            (new.patch > old.patch).then_some(Self::Patch)
+        }
+    }
+}
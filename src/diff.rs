@@ -0,0 +1,205 @@
+use crate::{AUTHORS, Author, Bump, Metadata, VERSION};
+use semver::Version;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
+
+impl Metadata {
+    /// Diff from this metadata to the new one
+    pub fn diff(&self, new: &Metadata) -> Diff {
+        Diff::new(self, new)
+    }
+}
+
+/// Diff
+///
+/// Entries of two [`Metadata`] by key, printed as a unified diff of
+/// `key: value` lines.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Diff {
+    pub entries: BTreeMap<String, DiffEntry>,
+}
+
+impl Diff {
+    pub fn new(old: &Metadata, new: &Metadata) -> Self {
+        let mut entries = BTreeMap::new();
+        for (key, old) in old.iter() {
+            let entry = match new.get(key) {
+                None => DiffEntry::Removed(old.clone()),
+                Some(new) if new == old => DiffEntry::Unchanged(old.clone()),
+                Some(new) => DiffEntry::Changed(Changed::new(key, old, new)),
+            };
+            entries.insert(key.clone(), entry);
+        }
+        for (key, new) in new.iter() {
+            if !old.contains_key(key) {
+                entries.insert(key.clone(), DiffEntry::Added(new.clone()));
+            }
+        }
+        Self { entries }
+    }
+
+    /// Whether both metadata are equal
+    pub fn is_empty(&self) -> bool {
+        self.entries
+            .values()
+            .all(|entry| matches!(entry, DiffEntry::Unchanged(_)))
+    }
+
+    pub fn added(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().filter_map(|(key, entry)| match entry {
+            DiffEntry::Added(value) => Some((&**key, &**value)),
+            _ => None,
+        })
+    }
+
+    pub fn removed(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().filter_map(|(key, entry)| match entry {
+            DiffEntry::Removed(value) => Some((&**key, &**value)),
+            _ => None,
+        })
+    }
+
+    pub fn changed(&self) -> impl Iterator<Item = (&str, &Changed)> {
+        self.entries.iter().filter_map(|(key, entry)| match entry {
+            DiffEntry::Changed(changed) => Some((&**key, changed)),
+            _ => None,
+        })
+    }
+}
+
+impl Display for Diff {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut lines = Vec::new();
+        for (key, entry) in &self.entries {
+            match entry {
+                DiffEntry::Unchanged(value) => lines.extend(prefixed(' ', key, value)),
+                DiffEntry::Added(value) => lines.extend(prefixed('+', key, value)),
+                DiffEntry::Removed(value) => lines.extend(prefixed('-', key, value)),
+                DiffEntry::Changed(changed) => {
+                    lines.extend(prefixed('-', key, &changed.old));
+                    lines.extend(prefixed('+', key, &changed.new));
+                }
+            }
+        }
+        let old = lines.iter().filter(|line| !line.starts_with('+')).count();
+        let new = lines.iter().filter(|line| !line.starts_with('-')).count();
+        writeln!(f, "--- old")?;
+        writeln!(f, "+++ new")?;
+        writeln!(f, "@@ -{} +{} @@", range(old), range(new))?;
+        for line in lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Diff entry
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DiffEntry {
+    Unchanged(String),
+    Added(String),
+    Removed(String),
+    Changed(Changed),
+}
+
+/// Changed value
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Changed {
+    pub old: String,
+    pub new: String,
+    pub value: ValueDiff,
+}
+
+impl Changed {
+    fn new(key: &str, old: &str, new: &str) -> Self {
+        let value = match key {
+            AUTHORS => ValueDiff::authors(old, new),
+            VERSION => ValueDiff::version(old, new),
+            _ => None,
+        };
+        Self {
+            old: old.to_owned(),
+            new: new.to_owned(),
+            value: value.unwrap_or(ValueDiff::Text),
+        }
+    }
+}
+
+/// Value-level diff of a changed value
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValueDiff {
+    /// Plain text, or a value that does not parse
+    Text,
+    /// Authors, matched by name
+    Authors {
+        inserted: Vec<Author>,
+        removed: Vec<Author>,
+        /// Whether the authors in both lists are in a different order
+        reordered: bool,
+    },
+    /// Semver version, with the bump if the new version is higher
+    Version(Option<Bump>),
+}
+
+impl ValueDiff {
+    fn authors(old: &str, new: &str) -> Option<Self> {
+        let parse = |value: &str| {
+            let mut authors = Author::from_value(value).ok()?;
+            authors.retain(|author| *author != Author::default());
+            Some(authors)
+        };
+        let old = parse(old)?;
+        let new = parse(new)?;
+        let contains = |authors: &[Author], author: &Author| {
+            authors.iter().any(|other| other.name == author.name)
+        };
+        let inserted = new
+            .iter()
+            .filter(|author| !contains(&old, author))
+            .cloned()
+            .collect();
+        let removed = old
+            .iter()
+            .filter(|author| !contains(&new, author))
+            .cloned()
+            .collect::<Vec<_>>();
+        let kept = |authors: &[Author], others: &[Author]| {
+            authors
+                .iter()
+                .filter(|author| contains(others, author))
+                .map(|author| author.name.clone())
+                .collect::<Vec<_>>()
+        };
+        Some(Self::Authors {
+            reordered: kept(&old, &new) != kept(&new, &old),
+            inserted,
+            removed,
+        })
+    }
+
+    fn version(old: &str, new: &str) -> Option<Self> {
+        let old = Version::parse(old).ok()?;
+        let new = Version::parse(new).ok()?;
+        Some(Self::Version(Bump::between(&old, &new)))
+    }
+}
+
+/// Lines of a `key: value` entry with the prefix
+fn prefixed(prefix: char, key: &str, value: &str) -> Vec<String> {
+    let mut lines = value.lines();
+    let first = lines.next().unwrap_or_default();
+    let mut prefixed = vec![format!("{prefix}{key}: {first}")];
+    prefixed.extend(lines.map(|line| format!("{prefix}{line}")));
+    prefixed
+}
+
+/// Range of a hunk header
+fn range(count: usize) -> String {
+    match count {
+        0 => "0,0".to_owned(),
+        1 => "1".to_owned(),
+        _ => format!("1,{count}"),
+    }
+}
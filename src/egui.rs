@@ -1,40 +1,109 @@
-use crate::{AUTHORS, DATE, DESCRIPTION, Metadata, NAME, VERSION};
-use chrono::NaiveDate;
+#[cfg(feature = "polars")]
+pub use self::frame::MetaDataFrameWidget;
+pub use self::history::History;
+pub use crate::DATE_FORMAT;
+
+use crate::{
+    AUTHORS, Author, Bump, DATE, DEFAULT_VERSION, DESCRIPTION, Field, Metadata, NAME, Schema,
+    VERSION, ValueType,
+};
+use chrono::Local;
 use egui::{
-    DragValue, Grid, Label, Response, TextEdit, Ui,
+    Button, DragValue, Grid, Id, Key, KeyboardShortcut, Label, Modifiers, Response, RichText,
+    TextEdit, Ui,
     cache::{ComputerMut, FrameCache},
 };
-use egui_extras::{Column, DatePickerButton, TableBuilder};
-use egui_phosphor::regular::{MINUS, PLUS};
-use semver::Version;
-use std::borrow::{Borrow, BorrowMut};
-use tracing::error;
-
-pub const DATE_FORMAT: &str = "%Y-%m-%d";
+use egui_extras::{Column, DatePickerButton, TableBody, TableBuilder};
+use egui_phosphor::regular::{
+    ARROW_DOWN, ARROW_UP, DOTS_SIX_VERTICAL, DOTS_THREE, MINUS, PLUS, SORT_ASCENDING, WARNING,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    collections::BTreeMap,
+    fmt::Display,
+    hash::Hash,
+    str::FromStr,
+};
 
 /// Metadata widget
 pub struct MetadataWidget<T> {
     metadata: T,
+    schema: Option<Schema>,
+    id_salt: Id,
     writable: bool,
     name: bool,
     description: bool,
     authors: bool,
     version: bool,
     date: bool,
+    others: bool,
+    history: bool,
 }
 
+const UNDO: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+const REDO: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
+
 impl<T> MetadataWidget<T> {
     pub fn new(metadata: T) -> Self {
         Self {
             metadata,
+            schema: None,
+            id_salt: Id::new("Metadata"),
             writable: false,
             name: true,
             description: true,
             authors: true,
             version: true,
             date: true,
+            others: true,
+            history: false,
         }
     }
+
+    pub fn with_id_salt(self, id_salt: impl Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            ..self
+        }
+    }
+
+    /// Shows the fields of the schema instead of the well-known keys
+    pub fn with_schema(self, schema: Schema) -> Self {
+        Self {
+            schema: Some(schema),
+            ..self
+        }
+    }
+
+    fn schema(&self) -> Schema {
+        self.schema.clone().unwrap_or_else(Schema::standard)
+    }
+
+    /// Fields to show
+    fn fields(&self) -> Vec<Field> {
+        self.schema()
+            .fields
+            .into_iter()
+            .filter(|field| match &*field.key {
+                NAME => self.name,
+                DESCRIPTION => self.description,
+                AUTHORS => self.authors,
+                VERSION => self.version,
+                DATE => self.date,
+                _ => true,
+            })
+            .collect()
+    }
+
+    /// Keys of the schema fields, shown or not
+    fn reserved(&self) -> Vec<String> {
+        self.schema()
+            .fields
+            .into_iter()
+            .map(|field| field.key)
+            .collect()
+    }
 }
 
 impl MetadataWidget<&mut Metadata> {
@@ -65,61 +134,80 @@ impl MetadataWidget<&mut Metadata> {
         Self { date, ..self }
     }
 
-    pub fn show(mut self, ui: &mut Ui) {
+    /// Shows the entries whose keys are not in the schema
+    pub fn with_others(self, others: bool) -> Self {
+        Self { others, ..self }
+    }
+
+    /// Keeps an undo/redo [`History`] in egui memory, driven by
+    /// <kbd>Ctrl+Z</kbd> and <kbd>Ctrl+Shift+Z</kbd> while the widget has
+    /// focus
+    pub fn with_history(self, history: bool) -> Self {
+        Self { history, ..self }
+    }
+
+    pub fn show(mut self, ui: &mut Ui) -> MetadataResponse {
         if self.writable {
-            self.writable(ui);
+            self.writable(ui)
         } else {
-            self.readable(ui);
+            MetadataResponse::new(self.readable(ui))
         }
     }
 }
 
 impl MetadataWidget<&Metadata> {
-    pub fn show(self, ui: &mut Ui) {
-        self.readable(ui);
+    pub fn show(self, ui: &mut Ui) -> MetadataResponse {
+        MetadataResponse::new(self.readable(ui))
     }
 }
 
 impl<T: Borrow<Metadata>> MetadataWidget<T> {
     /// Readable
     fn readable(&self, ui: &mut Ui) -> Response {
+        let fields = self.fields();
         Grid::new(ui.next_auto_id())
             .show(ui, |ui| {
                 let metadata = self.metadata.borrow();
-                if self.name {
-                    ui.label("Name");
-                    if let Some(name) = metadata.get(NAME) {
-                        ui.label(name);
-                    }
-                    ui.end_row();
-                }
-                if self.description {
-                    ui.label("Description");
-                    if let Some(description) = metadata.get(DESCRIPTION) {
-                        ui.add(Label::new(description).truncate());
-                    }
-                    ui.end_row();
-                }
-                if self.authors {
-                    ui.label("Authors");
-                    if let Some(authors) = metadata.get(AUTHORS) {
-                        ui.label(authors);
-                    }
-                    ui.end_row();
-                }
-                if self.version {
-                    ui.label("Version");
-                    if let Some(version) = metadata.get(VERSION) {
-                        ui.label(version);
+                for field in &fields {
+                    label(ui, field);
+                    if let Some(value) = metadata.get(&field.key) {
+                        match field.kind {
+                            ValueType::Text => {
+                                ui.add(Label::new(value).truncate());
+                            }
+                            ValueType::Url => {
+                                ui.hyperlink(value);
+                            }
+                            ValueType::Authors => match Author::from_value(value) {
+                                Ok(authors) => {
+                                    let names = authors
+                                        .iter()
+                                        .filter(|author| !author.name.is_empty())
+                                        .map(|author| &*author.name)
+                                        .collect::<Vec<_>>();
+                                    ui.label(names.join(", ")).on_hover_text(value);
+                                }
+                                Err(error) => {
+                                    ui.label(value);
+                                    warning(ui, &error.to_string());
+                                }
+                            },
+                            _ => {
+                                ui.label(value);
+                            }
+                        }
                     }
                     ui.end_row();
                 }
-                if self.date {
-                    ui.label("Date");
-                    if let Some(date) = metadata.get(DATE) {
-                        ui.label(date);
+                if self.others {
+                    let reserved = self.reserved();
+                    for (key, value) in metadata.iter() {
+                        if !reserved.contains(key) {
+                            ui.label(key);
+                            ui.label(value);
+                            ui.end_row();
+                        }
                     }
-                    ui.end_row();
                 }
             })
             .response
@@ -128,7 +216,48 @@ impl<T: Borrow<Metadata>> MetadataWidget<T> {
 
 impl<T: BorrowMut<Metadata>> MetadataWidget<T> {
     /// Writable
-    fn writable(&mut self, ui: &mut Ui) {
+    fn writable(&mut self, ui: &mut Ui) -> MetadataResponse {
+        let before = self.metadata.borrow().clone();
+        let id = ui.make_persistent_id(self.id_salt).with("History");
+        let mut history = self.history.then(|| {
+            ui.data(|data| data.get_temp::<History>(id))
+                .unwrap_or_default()
+        });
+        if let Some(history) = &mut history
+            && history.focused
+        {
+            // Consumed before the table is shown, so text edits do not undo
+            // on their own
+            if ui.input_mut(|input| input.consume_shortcut(&REDO)) {
+                history.redo(self.metadata.borrow_mut());
+            } else if ui.input_mut(|input| input.consume_shortcut(&UNDO)) {
+                history.undo(self.metadata.borrow_mut());
+            }
+        }
+        let old = self.metadata.borrow().clone();
+        let mut response = ui.scope(|ui| self.table(ui)).response;
+        if let Some(mut history) = history {
+            let time = ui.input(|input| input.time);
+            history.record(changes(&old, self.metadata.borrow()), time);
+            let (pressed, position) =
+                ui.input(|input| (input.pointer.any_pressed(), input.pointer.interact_pos()));
+            if pressed {
+                history.focused = position.is_some_and(|position| response.rect.contains(position));
+            }
+            ui.data_mut(|data| data.insert_temp(id, history));
+        }
+        let changes = changes(&before, self.metadata.borrow());
+        if !changes.is_empty() {
+            response.mark_changed();
+        }
+        MetadataResponse { response, changes }
+    }
+
+    /// Table
+    fn table(&mut self, ui: &mut Ui) {
+        let fields = self.fields();
+        let reserved = self.reserved();
+        let id = ui.make_persistent_id(self.id_salt);
         ui.style_mut().visuals.collapsing_header_frame = true;
         let height = ui.spacing().interact_size.y;
         TableBuilder::new(ui)
@@ -137,386 +266,548 @@ impl<T: BorrowMut<Metadata>> MetadataWidget<T> {
             .column(Column::remainder())
             .body(|mut body| {
                 let metadata = self.metadata.borrow_mut();
-                // Name
-                if self.name {
-                    body.row(height, |mut row| {
-                        row.col(|ui| {
-                            ui.label("Name");
-                        });
-                        row.col(|ui| {
-                            let name = metadata.entry(NAME.to_owned()).or_default();
-                            if ui
-                                .add(TextEdit::singleline(name).desired_width(f32::INFINITY))
-                                .lost_focus()
-                            {
-                                *name = name.trim().to_owned();
-                            }
-                        });
-                    });
-                }
-                // Description
-                if self.description {
-                    body.row(height, |mut row| {
-                        row.col(|ui| {
-                            ui.label("Description");
-                        });
-                        row.col(|ui| {
-                            let description = metadata.entry(DESCRIPTION.to_owned()).or_default();
-                            if ui
-                                .add(TextEdit::multiline(description).desired_width(f32::INFINITY))
-                                .lost_focus()
-                            {
-                                *description = description.trim().to_owned();
-                            }
-                        });
-                    });
-                }
-                // Authors
-                if self.authors {
-                    body.row(height, |mut row| {
-                        row.col(|ui| {
-                            ui.label("Authors");
-                        });
-                        row.col(|ui| {
-                            let value = metadata.entry(AUTHORS.to_owned()).or_default();
-                            let mut authors = ui.memory_mut(|memory| {
-                                memory.caches.cache::<AuthorsComputed>().get(value)
-                            });
-                            let mut changed = false;
-                            authors.retain_mut(|author| {
-                                let mut keep = true;
-                                ui.horizontal(|ui| {
-                                    keep = !ui.button(MINUS).clicked();
-                                    changed |= !keep;
-                                    let response = ui.add(
-                                        TextEdit::singleline(author).desired_width(f32::INFINITY),
-                                    );
-                                    changed |= response.changed();
-                                    if response.lost_focus() {
-                                        *author = author.trim().to_owned();
-                                        changed = true;
-                                    }
-                                });
-                                keep
-                            });
-                            if changed {
-                                *value = authors.join(",");
-                            }
-                            if ui.button(PLUS).clicked() {
-                                value.push(',');
-                            }
-                        });
-                    });
-                }
-                // Version
-                if self.version {
+                for field in &fields {
                     body.row(height, |mut row| {
                         row.col(|ui| {
-                            ui.label("Version");
+                            label(ui, field);
                         });
                         row.col(|ui| {
                             ui.horizontal(|ui| {
-                                let value = metadata.entry(VERSION.to_owned()).or_default();
-                                let mut version = Version::parse(value).unwrap_or_else(|error| {
-                                    error!(%error);
-                                    let version = Version::new(0, 0, 0);
-                                    *value = version.to_string();
-                                    version
-                                });
-                                let mut changed = false;
-                                ui.menu_button(version.to_string(), |ui| {
-                                    ui.visuals_mut().widgets.inactive = ui.visuals().widgets.active;
-                                    ui.horizontal(|ui| {
-                                        changed |=
-                                            ui.add(DragValue::new(&mut version.major)).changed();
-                                        changed |=
-                                            ui.add(DragValue::new(&mut version.minor)).changed();
-                                        changed |=
-                                            ui.add(DragValue::new(&mut version.patch)).changed();
-                                    });
-                                });
-                                if changed {
-                                    *value = version.to_string();
+                                let key = &*field.key;
+                                // A key is only written once it is turned on
+                                let mut checked = metadata.contains_key(key);
+                                if ui.checkbox(&mut checked, "").changed() {
+                                    if checked {
+                                        metadata.insert(key.to_owned(), initial(field));
+                                    } else {
+                                        metadata.remove(key);
+                                    }
+                                }
+                                if !checked {
+                                    return;
+                                }
+                                match field.kind {
+                                    ValueType::String | ValueType::Url => string(ui, metadata, key),
+                                    ValueType::Text => text(ui, metadata, key),
+                                    ValueType::List => list(ui, metadata, key),
+                                    ValueType::Authors => authors(ui, metadata, key),
+                                    ValueType::Semver => version(ui, metadata, key),
+                                    ValueType::Date => date(ui, metadata, key),
+                                    ValueType::Number => number(ui, metadata, key),
                                 }
                             });
                         });
                     });
                 }
-                // Date
-                if self.date {
-                    body.row(height, |mut row| {
-                        row.col(|ui| {
-                            ui.label("Date");
-                        });
-                        row.col(|ui| {
-                            ui.horizontal(|ui| {
-                                let value = metadata.entry(DATE.to_owned()).or_default();
-                                let mut date = NaiveDate::parse_from_str(value, DATE_FORMAT)
-                                    .unwrap_or_else(|error| {
-                                        error!(%error);
-                                        let date = NaiveDate::default();
-                                        *value = date.to_string();
-                                        date
-                                    });
-                                if ui
-                                    .add(DatePickerButton::new(&mut date).show_icon(false))
-                                    .changed()
-                                {
-                                    *value = date.to_string();
-                                }
-                            });
-                        });
+                if self.others {
+                    others(&mut body, metadata, &reserved, id.with("Others"), height);
+                }
+            });
+    }
+}
+
+/// Metadata response
+#[derive(Debug)]
+pub struct MetadataResponse {
+    pub response: Response,
+    /// Changed keys, with their old and new values
+    pub changes: BTreeMap<String, Change>,
+}
+
+impl MetadataResponse {
+    fn new(response: Response) -> Self {
+        Self {
+            response,
+            changes: BTreeMap::new(),
+        }
+    }
+
+    pub fn changed(&self) -> bool {
+        !self.changes.is_empty()
+    }
+}
+
+/// Change of a value, `None` when the key is absent
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Change {
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+fn changes(old: &Metadata, new: &Metadata) -> BTreeMap<String, Change> {
+    let mut changes = BTreeMap::new();
+    for (key, value) in old.iter() {
+        if new.get(key) != Some(value) {
+            changes.insert(
+                key.clone(),
+                Change {
+                    old: Some(value.clone()),
+                    new: new.get(key).cloned(),
+                },
+            );
+        }
+    }
+    for (key, value) in new.iter() {
+        if !old.contains_key(key) {
+            changes.insert(
+                key.clone(),
+                Change {
+                    old: None,
+                    new: Some(value.clone()),
+                },
+            );
+        }
+    }
+    changes
+}
+
+/// Entry whose key is not in the schema
+#[derive(Clone, Debug)]
+struct Entry {
+    key: String,
+    draft: String,
+}
+
+/// Key width in the others editor
+const KEY_WIDTH: f32 = 96.0;
+
+/// Others editor
+///
+/// Rows keep their order while keys are edited, a key is renamed only when
+/// the draft is neither empty nor taken.
+fn others(body: &mut TableBody, metadata: &mut Metadata, reserved: &[String], id: Id, height: f32) {
+    let mut entries = body
+        .ui_mut()
+        .data(|data| data.get_temp::<Vec<Entry>>(id))
+        .unwrap_or_default();
+    entries.retain(|entry| metadata.contains_key(&entry.key) && !reserved.contains(&entry.key));
+    for key in metadata.keys() {
+        if !reserved.contains(key) && !entries.iter().any(|entry| &entry.key == key) {
+            entries.push(Entry {
+                key: key.clone(),
+                draft: key.clone(),
+            });
+        }
+    }
+    let mut remove = None;
+    for (index, entry) in entries.iter_mut().enumerate() {
+        body.row(height, |mut row| {
+            row.col(|ui| {
+                ui.horizontal(|ui| {
+                    if ui.button(MINUS).clicked() {
+                        remove = Some(index);
+                    }
+                    let response =
+                        ui.add(TextEdit::singleline(&mut entry.draft).desired_width(KEY_WIDTH));
+                    let draft = entry.draft.trim();
+                    if draft != entry.key {
+                        if draft.is_empty() {
+                            warning(ui, "Empty key");
+                        } else if reserved.iter().any(|key| key == draft)
+                            || metadata.contains_key(draft)
+                        {
+                            warning(ui, "Duplicate key");
+                        } else if let Some(value) = metadata.remove(&entry.key) {
+                            metadata.insert(draft.to_owned(), value);
+                            entry.key = draft.to_owned();
+                        }
+                    }
+                    if response.lost_focus() {
+                        entry.draft = entry.key.clone();
+                    }
+                });
+            });
+            row.col(|ui| {
+                if let Some(value) = metadata.get_mut(&entry.key)
+                    && ui
+                        .add(TextEdit::singleline(value).desired_width(f32::INFINITY))
+                        .lost_focus()
+                {
+                    *value = value.trim().to_owned();
+                }
+            });
+        });
+    }
+    if let Some(index) = remove {
+        let entry = entries.remove(index);
+        metadata.remove(&entry.key);
+    }
+    body.row(height, |mut row| {
+        row.col(|ui| {
+            ui.horizontal(|ui| {
+                if ui.button(PLUS).on_hover_text("Add").clicked() {
+                    let key = (1..)
+                        .map(|index| format!("Key {index}"))
+                        .find(|key| !reserved.contains(key) && !metadata.contains_key(key))
+                        .unwrap_or_default();
+                    metadata.insert(key.clone(), String::new());
+                    entries.push(Entry {
+                        draft: key.clone(),
+                        key,
                     });
                 }
+                if ui.button(SORT_ASCENDING).on_hover_text("Sort").clicked() {
+                    entries.sort_by(|left, right| left.key.cmp(&right.key));
+                }
             });
+        });
+        row.col(|_| {});
+    });
+    body.ui_mut().data_mut(|data| data.insert_temp(id, entries));
+}
+
+/// Warning icon, with the message on hover
+fn warning(ui: &mut Ui, message: &str) {
+    ui.label(RichText::new(WARNING).color(ui.visuals().warn_fg_color))
+        .on_hover_text(message);
+}
+
+/// Field label, with the description on hover
+fn label(ui: &mut Ui, field: &Field) {
+    let response = ui.label(&field.key);
+    if let Some(description) = &field.description {
+        response.on_hover_text(description);
+    }
+}
+
+/// Initial value of a field turned on
+fn initial(field: &Field) -> String {
+    if let Some(default) = &field.default {
+        return default.clone();
+    }
+    match field.kind {
+        ValueType::Semver => DEFAULT_VERSION.to_owned(),
+        ValueType::Date => Local::now().date_naive().format(DATE_FORMAT).to_string(),
+        ValueType::Number => 0.to_string(),
+        ValueType::String
+        | ValueType::Text
+        | ValueType::List
+        | ValueType::Authors
+        | ValueType::Url => String::new(),
+    }
+}
+
+/// Single line text editor
+fn string(ui: &mut Ui, metadata: &mut Metadata, key: &str) {
+    let Some(value) = metadata.get_mut(key) else {
+        return;
+    };
+    if ui
+        .add(TextEdit::singleline(value).desired_width(f32::INFINITY))
+        .lost_focus()
+    {
+        *value = value.trim().to_owned();
+    }
+}
+
+/// Multiline text editor
+fn text(ui: &mut Ui, metadata: &mut Metadata, key: &str) {
+    let Some(value) = metadata.get_mut(key) else {
+        return;
+    };
+    if ui
+        .add(TextEdit::multiline(value).desired_width(f32::INFINITY))
+        .lost_focus()
+    {
+        *value = value.trim().to_owned();
+    }
+}
+
+/// Invalid value editor, keeps the stored text editable instead of
+/// replacing it
+fn invalid(ui: &mut Ui, metadata: &mut Metadata, key: &str, error: impl Display) {
+    warning(ui, &error.to_string());
+    string(ui, metadata, key);
+}
+
+/// Comma separated list editor
+fn list(ui: &mut Ui, metadata: &mut Metadata, key: &str) {
+    let Some(value) = metadata.get_mut(key) else {
+        return;
+    };
+    ui.vertical(|ui| {
+        let mut items =
+            ui.memory_mut(|memory| memory.caches.cache::<ListComputed>().get(value.as_str()));
+        let mut changed = false;
+        items.retain_mut(|item| {
+            let mut keep = true;
+            ui.horizontal(|ui| {
+                keep = !ui.button(MINUS).clicked();
+                changed |= !keep;
+                let response = ui.add(TextEdit::singleline(item).desired_width(f32::INFINITY));
+                changed |= response.changed();
+                if response.lost_focus() {
+                    *item = item.trim().to_owned();
+                    changed = true;
+                }
+            });
+            keep
+        });
+        if changed {
+            *value = items.join(",");
+        }
+        if ui.button(PLUS).clicked() {
+            value.push(',');
+        }
+    });
+}
+
+/// Authors editor
+///
+/// Authors are reordered by dragging the handle or with the arrow buttons.
+fn authors(ui: &mut Ui, metadata: &mut Metadata, key: &str) {
+    let Some(value) = metadata.get_mut(key) else {
+        return;
+    };
+    let Some(mut authors) =
+        ui.memory_mut(|memory| memory.caches.cache::<AuthorsComputed>().get(value.as_str()))
+    else {
+        return invalid(ui, metadata, key, "Invalid authors");
+    };
+    let id = ui.make_persistent_id(key);
+    ui.vertical(|ui| {
+        let mut changed = false;
+        let mut remove = None;
+        let mut moved = None;
+        let count = authors.len();
+        for (index, author) in authors.iter_mut().enumerate() {
+            let response = ui
+                .horizontal(|ui| {
+                    ui.dnd_drag_source(id.with(index), Dragged { id, index }, |ui| {
+                        ui.label(DOTS_SIX_VERTICAL);
+                    })
+                    .response
+                    .on_hover_text("Drag to reorder");
+                    if ui
+                        .add_enabled(index > 0, Button::new(ARROW_UP))
+                        .on_hover_text("Move up")
+                        .clicked()
+                    {
+                        moved = Some((index, index - 1));
+                    }
+                    if ui
+                        .add_enabled(index + 1 < count, Button::new(ARROW_DOWN))
+                        .on_hover_text("Move down")
+                        .clicked()
+                    {
+                        moved = Some((index, index + 1));
+                    }
+                    if ui.button(MINUS).clicked() {
+                        remove = Some(index);
+                    }
+                    ui.menu_button(DOTS_THREE, |ui| {
+                        changed |= details(ui, author);
+                    });
+                    let response =
+                        ui.add(TextEdit::singleline(&mut author.name).desired_width(f32::INFINITY));
+                    changed |= response.changed();
+                    if response.lost_focus() {
+                        author.name = author.name.trim().to_owned();
+                        changed = true;
+                    }
+                })
+                .response;
+            if let Some(dragged) = response.dnd_hover_payload::<Dragged>()
+                && dragged.id == id
+            {
+                let y = if dragged.index < index {
+                    response.rect.bottom()
+                } else {
+                    response.rect.top()
+                };
+                let stroke = ui.visuals().selection.stroke;
+                ui.painter().hline(response.rect.x_range(), y, stroke);
+            }
+            if let Some(dragged) = response.dnd_release_payload::<Dragged>()
+                && dragged.id == id
+            {
+                moved = Some((dragged.index, index));
+            }
+        }
+        if let Some(index) = remove {
+            authors.remove(index);
+            changed = true;
+        }
+        if let Some((from, to)) = moved
+            && from != to
+        {
+            let author = authors.remove(from);
+            authors.insert(to, author);
+            changed = true;
+        }
+        if ui.button(PLUS).clicked() {
+            authors.push(Author::default());
+            changed = true;
+        }
+        // The frame cache is keyed by the value, so it follows the new order
+        if changed {
+            *value = Author::to_value(&authors);
+        }
+    });
+}
+
+/// Dragged author
+#[derive(Clone, Copy, Debug)]
+struct Dragged {
+    id: Id,
+    index: usize,
+}
+
+/// Author details editor
+fn details(ui: &mut Ui, author: &mut Author) -> bool {
+    let mut changed = false;
+    Grid::new(ui.next_auto_id()).show(ui, |ui| {
+        for (label, field) in [
+            ("Given name", &mut author.given),
+            ("Family name", &mut author.family),
+            ("Email", &mut author.email),
+            ("ORCID", &mut author.orcid),
+            ("Affiliation", &mut author.affiliation),
+            ("Role", &mut author.role),
+        ] {
+            ui.label(label);
+            let mut text = field.clone().unwrap_or_default();
+            if ui.text_edit_singleline(&mut text).changed() {
+                *field = (!text.is_empty()).then_some(text);
+                changed = true;
+            }
+            ui.end_row();
+        }
+    });
+    changed
+}
+
+/// Version editor
+fn version(ui: &mut Ui, metadata: &mut Metadata, key: &str) {
+    let mut version = match metadata.get_version(key) {
+        Ok(version) => version,
+        Err(error) => return invalid(ui, metadata, key, error),
+    };
+    let mut changed = false;
+    ui.menu_button(version.to_string(), |ui| {
+        ui.visuals_mut().widgets.inactive = ui.visuals().widgets.active;
+        ui.horizontal(|ui| {
+            changed |= ui.add(DragValue::new(&mut version.major)).changed();
+            changed |= ui.add(DragValue::new(&mut version.minor)).changed();
+            changed |= ui.add(DragValue::new(&mut version.patch)).changed();
+        });
+        Grid::new(ui.next_auto_id()).show(ui, |ui| {
+            ui.label("Pre-release");
+            changed |= identifiers(ui, &mut version.pre, (key, "Pre"));
+            ui.end_row();
+            ui.label("Build");
+            changed |= identifiers(ui, &mut version.build, (key, "Build"));
+            ui.end_row();
+        });
+        ui.horizontal(|ui| {
+            for (bump, text) in [
+                (Bump::Major, "Major"),
+                (Bump::Minor, "Minor"),
+                (Bump::Patch, "Patch"),
+            ] {
+                if ui
+                    .button(text)
+                    .on_hover_text(format!("Bump {}", text.to_lowercase()))
+                    .clicked()
+                {
+                    version = bump.apply(&version);
+                    changed = true;
+                }
+            }
+        });
+    });
+    if changed {
+        metadata.insert_version(key, &version);
+    }
+}
+
+/// Pre-release or build identifiers editor
+///
+/// The text is kept as a draft and only applied once it parses.
+fn identifiers<T: Display + FromStr>(ui: &mut Ui, identifiers: &mut T, id_salt: impl Hash) -> bool
+where
+    T::Err: Display,
+{
+    let id = ui.make_persistent_id(id_salt);
+    let mut draft = ui
+        .data(|data| data.get_temp::<String>(id))
+        .unwrap_or_else(|| identifiers.to_string());
+    let response = ui.add(TextEdit::singleline(&mut draft).desired_width(KEY_WIDTH));
+    let mut changed = false;
+    match draft.trim().parse::<T>() {
+        Ok(parsed) if response.changed() => {
+            *identifiers = parsed;
+            changed = true;
+        }
+        Ok(_) => {}
+        Err(error) => warning(ui, &error.to_string()),
+    }
+    if response.has_focus() {
+        ui.data_mut(|data| data.insert_temp(id, draft));
+    } else {
+        ui.data_mut(|data| data.remove::<String>(id));
+    }
+    changed
+}
+
+/// Date editor
+fn date(ui: &mut Ui, metadata: &mut Metadata, key: &str) {
+    let mut date = match metadata.get_date(key) {
+        Ok(date) => date,
+        Err(error) => return invalid(ui, metadata, key, error),
+    };
+    if ui
+        .add(
+            DatePickerButton::new(&mut date)
+                .id_salt(key)
+                .show_icon(false),
+        )
+        .changed()
+    {
+        metadata.insert_date(key, date);
+    }
+}
+
+/// Number editor
+fn number(ui: &mut Ui, metadata: &mut Metadata, key: &str) {
+    let Some(value) = metadata.get_mut(key) else {
+        return;
+    };
+    let mut number = match value.trim().parse::<f64>() {
+        Ok(number) => number,
+        Err(error) => return invalid(ui, metadata, key, error),
+    };
+    if ui.add(DragValue::new(&mut number)).changed() {
+        *value = number.to_string();
     }
 }
 
 /// Authors computed
-type AuthorsComputed = FrameCache<Vec<String>, AuthorsComputer>;
+type AuthorsComputed = FrameCache<Option<Vec<Author>>, AuthorsComputer>;
 
 /// Authors computer
 #[derive(Default)]
 struct AuthorsComputer;
 
-impl ComputerMut<&str, Vec<String>> for AuthorsComputer {
+impl ComputerMut<&str, Option<Vec<Author>>> for AuthorsComputer {
+    fn compute(&mut self, key: &str) -> Option<Vec<Author>> {
+        Author::from_value(key).ok()
+    }
+}
+
+/// List computed
+type ListComputed = FrameCache<Vec<String>, ListComputer>;
+
+/// List computer
+#[derive(Default)]
+struct ListComputer;
+
+impl ComputerMut<&str, Vec<String>> for ListComputer {
     fn compute(&mut self, key: &str) -> Vec<String> {
         key.split(',').map(ToOwned::to_owned).collect()
     }
 }
 
-// /// Writable
-// fn writable(metadata: &mut Metadata, ui: &mut Ui) {
-//     ui.style_mut().visuals.collapsing_header_frame = true;
-//     let height = ui.spacing().interact_size.y;
-//     TableBuilder::new(ui)
-//         .resizable(true)
-//         .column(Column::auto())
-//         .column(Column::remainder())
-//         .body(|mut body| {
-//             // Name
-//             body.row(height, |mut row| {
-//                 row.col(|ui| {
-//                     ui.label("Name");
-//                 });
-//                 row.col(|ui| {
-//                     if ui
-//                         .add(TextEdit::singleline(&mut metadata.name).desired_width(f32::INFINITY))
-//                         .lost_focus()
-//                     {
-//                         metadata.name = metadata.name.trim().to_owned();
-//                     }
-//                 });
-//             });
-//             // Description
-//             body.row(height, |mut row| {
-//                 row.col(|ui| {
-//                     ui.label("Description");
-//                 });
-//                 row.col(|ui| {
-//                     if ui
-//                         .add(
-//                             TextEdit::multiline(&mut metadata.description)
-//                                 .desired_width(f32::INFINITY),
-//                         )
-//                         .lost_focus()
-//                     {
-//                         metadata.description = metadata.description.trim().to_owned();
-//                     }
-//                 });
-//             });
-//             // Authors
-//             body.row(height, |mut row| {
-//                 row.col(|ui| {
-//                     ui.label("Authors");
-//                 });
-//                 row.col(|ui| {
-//                     metadata.authors.retain_mut(|author| {
-//                         let mut keep = true;
-//                         ui.horizontal(|ui| {
-//                             keep = !ui.button(MINUS).clicked();
-//                             if ui
-//                                 .add(TextEdit::singleline(author).desired_width(f32::INFINITY))
-//                                 .lost_focus()
-//                             {
-//                                 *author = author.trim().to_owned();
-//                             }
-//                         });
-//                         keep
-//                     });
-//                     if ui.button(PLUS).clicked() {
-//                         metadata.authors.push(String::new());
-//                     }
-//                 });
-//             });
-//             // Version
-//             body.row(height, |mut row| {
-//                 row.col(|ui| {
-//                     ui.label("Version");
-//                 });
-//                 row.col(|ui| {
-//                     ui.horizontal(|ui| {
-//                         let mut checked = metadata.version.is_some();
-//                         if ui.checkbox(&mut checked, "").changed() {
-//                             metadata.version = checked.then_some(Version::new(0, 0, 0));
-//                         }
-//                         if let Some(version) = &mut metadata.version {
-//                             ui.menu_button(version.to_string(), |ui| {
-//                                 ui.visuals_mut().widgets.inactive = ui.visuals().widgets.active;
-//                                 ui.horizontal(|ui| {
-//                                     ui.add(DragValue::new(&mut version.major));
-//                                     ui.add(DragValue::new(&mut version.minor));
-//                                     ui.add(DragValue::new(&mut version.patch));
-//                                 });
-//                             });
-//                         }
-//                     });
-//                 });
-//             });
-//             // Date
-//             body.row(height, |mut row| {
-//                 row.col(|ui| {
-//                     ui.label("Date");
-//                 });
-//                 row.col(|ui| {
-//                     ui.horizontal(|ui| {
-//                         let mut checked = metadata.date.is_some();
-//                         if ui.checkbox(&mut checked, "").changed() {
-//                             metadata.date = checked.then_some(Local::now().date_naive());
-//                         }
-//                         if let Some(date) = &mut metadata.date {
-//                             ui.add(DatePickerButton::new(date).show_icon(false));
-//                         }
-//                     });
-//                 });
-//             });
-//         });
-// }
-
-// /// Writable
-// fn writable(metadata: &mut Metadata, ui: &mut Ui) {
-//     ui.style_mut().visuals.collapsing_header_frame = true;
-//     let height = ui.spacing().interact_size.y;
-//     TableBuilder::new(ui)
-//         .resizable(true)
-//         .column(Column::auto())
-//         .column(Column::remainder())
-//         .body(|mut body| {
-//             // Name
-//             body.row(height, |mut row| {
-//                 row.col(|ui| {
-//                     ui.label("Name");
-//                 });
-//                 row.col(|ui| {
-//                     if let Some(name) = metadata.get_mut(NAME) {
-//                         if ui
-//                             .add(TextEdit::singleline(name).desired_width(f32::INFINITY))
-//                             .lost_focus()
-//                         {
-//                             *name = name.trim().to_owned();
-//                         }
-//                     } else {
-//                         if ui.checkbox(&mut false, "").changed() {
-//                             metadata.insert(NAME.to_owned(), String::new());
-//                         }
-//                     }
-//                 });
-//             });
-//             // Description
-//             if let Some(description) = metadata.get_mut(DESCRIPTION) {
-//                 body.row(height, |mut row| {
-//                     row.col(|ui| {
-//                         ui.label("Description");
-//                     });
-//                     row.col(|ui| {
-//                         if ui
-//                             .add(TextEdit::multiline(description).desired_width(f32::INFINITY))
-//                             .lost_focus()
-//                         {
-//                             *description = description.trim().to_owned();
-//                         }
-//                     });
-//                 });
-//             }
-//             // Authors
-//             if let Some(authors) = metadata.get_mut(AUTHORS) {
-//                 body.row(height, |mut row| {
-//                     row.col(|ui| {
-//                         ui.label("Authors");
-//                     });
-//                     let mut authors: Vec<_> = authors.split(",").map(str::trim).collect();
-//                     row.col(|ui| {
-//                         authors.retain_mut(|author| {
-//                             let mut keep = true;
-//                             ui.horizontal(|ui| {
-//                                 keep = !ui.button(MINUS).clicked();
-//                                 if ui
-//                                     .add(TextEdit::singleline(author).desired_width(f32::INFINITY))
-//                                     .lost_focus()
-//                                 {
-//                                     *author = author.trim().to_owned();
-//                                 }
-//                             });
-//                             keep
-//                         });
-//                         if ui.button(PLUS).clicked() {
-//                             authors.push(String::new());
-//                         }
-//                     });
-//                 });
-//             }
-//             // Version
-//             if let Some(version) = metadata.get_mut(VERSION) {
-//                 body.row(height, |mut row| {
-//                     row.col(|ui| {
-//                         ui.label("Version");
-//                     });
-//                     row.col(|ui| {
-//                         if ui
-//                             .add(TextEdit::singleline(version).desired_width(f32::INFINITY))
-//                             .lost_focus()
-//                         {
-//                             *version = version.trim().to_owned();
-//                         }
-//                     });
-//                 });
-//             }
-//             // Date
-//             body.row(height, |mut row| {
-//                 row.col(|ui| {
-//                     ui.label("Date");
-//                 });
-//                 // if let Ok(mut parsed) = NaiveDate::parse_from_str(date, DATE_FORMAT) {
-//                 //     if ui
-//                 //         .add(DatePickerButton::new(&mut parsed).show_icon(false))
-//                 //         .changed()
-//                 //     {
-//                 //         *date = parsed.format(DATE_FORMAT).to_string();
-//                 //     }
-//                 // } else {
-//                 //     ui.label(&*date);
-//                 // }
-//                 row.col(|ui| {
-//                     ui.horizontal(|ui| {
-//                         let value = metadata.get_mut(DATE);
-//                         let mut checked = value.is_some();
-//                         let value = value.unwrap_or(&mut String::new());
-//                         let t = NaiveDate::parse_from_str(value, DATE_FORMAT);
-//                         if ui.checkbox(&mut checked, "").changed() {
-//                             metadata.date = checked.then_some(Local::now().date_naive());
-//                         }
-//                         if let Some(date) = &mut metadata.date {}
-//                     });
-//                 });
-//                 // row.col(|ui| {
-//                 //     ui.horizontal(|ui| {
-//                 //         let mut checked = metadata.date.is_some();
-//                 //         if ui.checkbox(&mut checked, "").changed() {
-//                 //             metadata.date = checked.then_some(Local::now().date_naive());
-//                 //         }
-//                 //         if let Some(date) = &mut metadata.date {}
-//                 //     });
-//                 // });
-//             });
-//         });
-// }
+#[cfg(feature = "polars")]
+mod frame;
+mod history;
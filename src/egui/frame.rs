@@ -0,0 +1,129 @@
+use super::MetadataWidget;
+use crate::polars::MetaDataFrame;
+use egui::{Button, Id, Response, ScrollArea, Ui};
+use egui_extras::{Column, TableBuilder};
+use egui_phosphor::regular::{CARET_LEFT, CARET_RIGHT};
+use std::{borrow::Borrow, hash::Hash};
+
+/// Meta data frame widget
+///
+/// Shows the metadata next to a virtualized table of the data, with the data
+/// type under each column name.
+pub struct MetaDataFrameWidget<T> {
+    frame: T,
+    id_salt: Id,
+    page_size: Option<usize>,
+}
+
+impl<T> MetaDataFrameWidget<T> {
+    pub fn new(frame: T) -> Self {
+        Self {
+            frame,
+            id_salt: Id::new("MetaDataFrame"),
+            page_size: None,
+        }
+    }
+
+    pub fn with_id_salt(self, id_salt: impl Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            ..self
+        }
+    }
+
+    /// Splits the rows into pages of the given size
+    pub fn with_page_size(self, page_size: Option<usize>) -> Self {
+        Self { page_size, ..self }
+    }
+}
+
+impl<T: Borrow<MetaDataFrame>> MetaDataFrameWidget<T> {
+    pub fn show(self, ui: &mut Ui) -> Response {
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                MetadataWidget::new(&self.frame.borrow().meta)
+                    .with_id_salt(self.id_salt.with("Metadata"))
+                    .show(ui);
+            });
+            ui.separator();
+            ui.vertical(|ui| self.data(ui));
+        })
+        .response
+    }
+
+    /// Data
+    fn data(&self, ui: &mut Ui) {
+        let data = &self.frame.borrow().data;
+        let id = ui.make_persistent_id(self.id_salt);
+        let rows = data.height();
+        ui.label(format!("{rows} rows, {} columns", data.width()));
+        let (offset, length) = match self.page_size {
+            Some(page_size) if page_size > 0 => {
+                let pages = rows.div_ceil(page_size).max(1);
+                let mut page = ui
+                    .data(|data| data.get_temp::<usize>(id.with("Page")))
+                    .unwrap_or_default()
+                    .min(pages - 1);
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(page > 0, Button::new(CARET_LEFT)).clicked() {
+                        page -= 1;
+                    }
+                    ui.label(format!("{} / {pages}", page + 1));
+                    if ui
+                        .add_enabled(page + 1 < pages, Button::new(CARET_RIGHT))
+                        .clicked()
+                    {
+                        page += 1;
+                    }
+                });
+                ui.data_mut(|data| data.insert_temp(id.with("Page"), page));
+                let offset = page * page_size;
+                (offset, page_size.min(rows - offset))
+            }
+            _ => (0, rows),
+        };
+        let height = ui.spacing().interact_size.y;
+        ScrollArea::horizontal()
+            .id_salt(id.with("Scroll"))
+            .show(ui, |ui| {
+                TableBuilder::new(ui)
+                    .id_salt(id.with("Table"))
+                    .striped(true)
+                    .column(Column::auto())
+                    .columns(Column::auto().resizable(true), data.width())
+                    .header(2.0 * height, |mut header| {
+                        header.col(|ui| {
+                            ui.strong("#");
+                        });
+                        for column in data.get_columns() {
+                            header.col(|ui| {
+                                ui.vertical(|ui| {
+                                    ui.strong(column.name().as_str());
+                                    ui.small(column.dtype().to_string());
+                                });
+                            });
+                        }
+                    })
+                    .body(|body| {
+                        // Only the visible rows are laid out
+                        body.rows(height, length, |mut row| {
+                            let index = offset + row.index();
+                            row.col(|ui| {
+                                ui.label(index.to_string());
+                            });
+                            for column in data.get_columns() {
+                                row.col(|ui| {
+                                    if let Ok(value) = column.get(index) {
+                                        // Strings without the quotes of `Display`
+                                        match value.get_str() {
+                                            Some(text) => ui.label(text),
+                                            None => ui.label(value.to_string()),
+                                        };
+                                    }
+                                });
+                            }
+                        });
+                    });
+            });
+    }
+}
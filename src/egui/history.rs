@@ -0,0 +1,111 @@
+use super::Change;
+use crate::Metadata;
+use std::collections::BTreeMap;
+
+/// Maximum number of undo steps
+const CAPACITY: usize = 100;
+
+/// Changes of the same keys within this interval, in seconds, are merged
+/// into one step
+const MERGE_INTERVAL: f64 = 1.0;
+
+/// History of metadata edits
+///
+/// Kept in egui memory by [`MetadataWidget::with_history`], or held by the
+/// caller and fed with [`MetadataResponse::changes`].
+///
+/// [`MetadataWidget::with_history`]: super::MetadataWidget::with_history
+/// [`MetadataResponse::changes`]: super::MetadataResponse::changes
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    undo: Vec<Step>,
+    redo: Vec<Step>,
+    pub(super) focused: bool,
+}
+
+impl History {
+    /// Records changes made at `time`, merging typing bursts into the last
+    /// step
+    pub fn record(&mut self, changes: BTreeMap<String, Change>, time: f64) {
+        if changes.is_empty() {
+            return;
+        }
+        self.redo.clear();
+        if let Some(last) = self.undo.last_mut()
+            && time - last.time < MERGE_INTERVAL
+            && last.changes.keys().eq(changes.keys())
+        {
+            for (key, change) in changes {
+                if let Some(last) = last.changes.get_mut(&key) {
+                    last.new = change.new;
+                }
+            }
+            last.changes.retain(|_, change| change.old != change.new);
+            last.time = time;
+            if last.changes.is_empty() {
+                self.undo.pop();
+            }
+            return;
+        }
+        self.undo.push(Step { changes, time });
+        if self.undo.len() > CAPACITY {
+            self.undo.remove(0);
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Reverts the last step, returns `false` if there is nothing to undo
+    pub fn undo(&mut self, metadata: &mut Metadata) -> bool {
+        let Some(step) = self.undo.pop() else {
+            return false;
+        };
+        for (key, change) in &step.changes {
+            apply(metadata, key, change.old.clone());
+        }
+        self.redo.push(step);
+        true
+    }
+
+    /// Reapplies the last undone step, returns `false` if there is nothing to
+    /// redo
+    pub fn redo(&mut self, metadata: &mut Metadata) -> bool {
+        let Some(step) = self.redo.pop() else {
+            return false;
+        };
+        for (key, change) in &step.changes {
+            apply(metadata, key, change.new.clone());
+        }
+        // Not merged with the next edit
+        self.undo.push(Step {
+            time: f64::NEG_INFINITY,
+            ..step
+        });
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+/// Step
+#[derive(Clone, Debug)]
+struct Step {
+    changes: BTreeMap<String, Change>,
+    time: f64,
+}
+
+fn apply(metadata: &mut Metadata, key: &str, value: Option<String>) {
+    match value {
+        Some(value) => metadata.insert(key.to_owned(), value),
+        None => metadata.remove(key),
+    };
+}
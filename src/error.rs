@@ -7,10 +7,38 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 /// Error
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(r#"metadata date "{0}""#)]
-    Date(#[from] chrono::ParseError),
-    #[error(r#"metadata version "{0}""#)]
-    Version(#[from] semver::Error),
+    #[error(r#"metadata authors "{key}": "{value}""#)]
+    Authors { key: String, value: String },
+    #[error(r#"metadata conflict "{key}": {values:?}"#)]
+    Conflict { key: String, values: Vec<String> },
+    #[error(r#"metadata date "{key}": "{value}" ({source})"#)]
+    Date {
+        key: String,
+        value: String,
+        source: chrono::ParseError,
+    },
+    #[error(r#"metadata format "{key}": "{value}""#)]
+    Format { key: String, value: String },
+    #[error(r#"metadata missing "{key}""#)]
+    Missing { key: String },
+    #[error(r#"metadata version "{key}": "{value}" ({source})"#)]
+    Version {
+        key: String,
+        value: String,
+        source: semver::Error,
+    },
+    #[cfg(feature = "json")]
+    #[error(r#"json "{0}""#)]
+    Json(#[from] serde_json::Error),
+    #[cfg(feature = "toml")]
+    #[error(r#"toml "{0}""#)]
+    Toml(#[from] toml::de::Error),
+    #[cfg(feature = "regex")]
+    #[error(r#"regex "{0}""#)]
+    Regex(#[from] regex::Error),
     #[error(r#"io "{0}""#)]
     Io(#[from] io::Error),
+    #[cfg(feature = "polars")]
+    #[error(r#"polars "{0}""#)]
+    Polars(#[from] polars::error::PolarsError),
 }
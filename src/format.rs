@@ -0,0 +1,143 @@
+use crate::{DATE, DATE_FORMAT, DEFAULT_DATE, DEFAULT_VERSION, Metadata, NAME, VERSION};
+use chrono::NaiveDate;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Formatter, Write};
+
+/// Format
+///
+/// Layout used to display [`Metadata`] as `Name[Version]{separator}Date`.
+/// Default version and date values are skipped.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Format {
+    name: bool,
+    version: bool,
+    date: bool,
+    separator: String,
+    version_style: VersionStyle,
+    date_format: String,
+}
+
+impl Format {
+    pub fn new() -> Self {
+        Self {
+            name: true,
+            version: true,
+            date: true,
+            separator: " ".to_owned(),
+            version_style: VersionStyle::Short,
+            date_format: DATE_FORMAT.to_owned(),
+        }
+    }
+
+    pub fn with_name(self, name: bool) -> Self {
+        Self { name, ..self }
+    }
+
+    pub fn with_version(self, version: bool) -> Self {
+        Self { version, ..self }
+    }
+
+    pub fn with_date(self, date: bool) -> Self {
+        Self { date, ..self }
+    }
+
+    pub fn with_separator(self, separator: impl Into<String>) -> Self {
+        Self {
+            separator: separator.into(),
+            ..self
+        }
+    }
+
+    pub fn with_version_style(self, version_style: VersionStyle) -> Self {
+        Self {
+            version_style,
+            ..self
+        }
+    }
+
+    /// Date format, in [`chrono::format::strftime`] syntax
+    pub fn with_date_format(self, date_format: impl Into<String>) -> Self {
+        Self {
+            date_format: date_format.into(),
+            ..self
+        }
+    }
+
+    pub(crate) fn write(&self, metadata: &Metadata, f: &mut Formatter) -> fmt::Result {
+        let mut empty = true;
+        if self.name
+            && let Some(name) = metadata.get(NAME)
+        {
+            f.write_str(name)?;
+            empty = name.is_empty();
+        }
+        if self.version
+            && let Some(version) = metadata.get(VERSION)
+            && version != DEFAULT_VERSION
+        {
+            f.write_char('[')?;
+            match Version::parse(version) {
+                Ok(version) => self.version_style.fmt(&version, f)?,
+                Err(_) => f.write_str(version)?,
+            }
+            f.write_char(']')?;
+            empty = false;
+        }
+        if self.date
+            && let Some(date) = metadata.get(DATE)
+            && date != DEFAULT_DATE
+        {
+            if !empty {
+                f.write_str(&self.separator)?;
+            }
+            match NaiveDate::parse_from_str(date, DATE_FORMAT) {
+                Ok(date) => write!(f, "{}", date.format(&self.date_format))?,
+                Err(_) => f.write_str(date)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Version style
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum VersionStyle {
+    /// `1.0.0-rc.1+build.5`
+    Full,
+    /// `1.0.0-rc.1`
+    Release,
+    /// `1.2.3`, `1.2`, `1`: trailing zero components are dropped when there is
+    /// no pre-release or build metadata
+    #[default]
+    Short,
+}
+
+impl VersionStyle {
+    fn fmt(&self, version: &Version, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Full => write!(f, "{version}"),
+            Self::Release => {
+                write!(f, "{}.{}.{}", version.major, version.minor, version.patch)?;
+                if !version.pre.is_empty() {
+                    write!(f, "-{}", version.pre)?;
+                }
+                Ok(())
+            }
+            Self::Short if !version.pre.is_empty() || !version.build.is_empty() => {
+                write!(f, "{version}")
+            }
+            Self::Short if version.patch != 0 => {
+                write!(f, "{}.{}.{}", version.major, version.minor, version.patch)
+            }
+            Self::Short if version.minor != 0 => write!(f, "{}.{}", version.major, version.minor),
+            Self::Short => write!(f, "{}", version.major),
+        }
+    }
+}
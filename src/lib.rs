@@ -1,7 +1,20 @@
 #![feature(debug_closure_helpers)]
 
-// pub use self::error::{Error, Result};
+pub use self::{
+    author::Author,
+    bump::Bump,
+    diff::{Changed, Diff, DiffEntry, ValueDiff},
+    error::{Error, Result},
+    format::{Format, VersionStyle},
+    merge::{Conflict, MergePolicy, Merger, Resolver},
+    record::MetadataRecord,
+    schema::{Field, Schema, ValueType},
+    stem::FILE_STEM_LENGTH,
+    validator::{CustomCheck, Diagnostic, Rule, Severity, Validator},
+};
 
+use chrono::{Local, NaiveDate};
+use semver::Version;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::BTreeMap,
@@ -13,52 +26,175 @@ pub const AUTHORS: &str = "Authors";
 pub const DATE: &str = "Date";
 pub const DESCRIPTION: &str = "Description";
 pub const NAME: &str = "Name";
+pub const PROVENANCE: &str = "Provenance";
 pub const VERSION: &str = "Version";
 
 pub const DEFAULT_DATE: &str = "1970-01-01";
 pub const DEFAULT_VERSION: &str = "0.0.0";
 
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
 /// Metadata
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Metadata(pub BTreeMap<String, String>);
 
 impl Metadata {
-    pub fn format(&self, separator: &str) -> impl Debug + Display {
-        from_fn(move |f| {
-            if let Some(name) = self.get(NAME) {
-                write!(f, "{name}")?;
-            }
-            if let Some(version) = self.get(VERSION)
-                && version != DEFAULT_VERSION
-            {
-                write!(f, "[{}]", version.trim_start_matches(['0', '.']))?;
+    /// Name
+    pub fn name(&self) -> Option<&str> {
+        self.get(NAME).map(String::as_str)
+    }
+
+    /// Description
+    pub fn description(&self) -> Option<&str> {
+        self.get(DESCRIPTION).map(String::as_str)
+    }
+
+    /// Names of the authors, without empty entries
+    ///
+    /// A malformed value has no names, [`Metadata::structured_authors`]
+    /// returns its error.
+    pub fn authors(&self) -> Vec<String> {
+        self.structured_authors()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|author| author.name)
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// Authors, without empty entries
+    pub fn structured_authors(&self) -> Result<Vec<Author>> {
+        match self.get(AUTHORS) {
+            Some(_) => self.get_authors(AUTHORS),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Version
+    pub fn version(&self) -> Result<Version> {
+        self.get_version(VERSION)
+    }
+
+    /// Date
+    pub fn date(&self) -> Result<NaiveDate> {
+        self.get_date(DATE)
+    }
+
+    /// Parses the value of any key as authors, without empty entries
+    pub fn get_authors(&self, key: &str) -> Result<Vec<Author>> {
+        let value = self.required(key)?;
+        let mut authors = Author::from_value(value).map_err(|_| Error::Authors {
+            key: key.to_owned(),
+            value: value.to_owned(),
+        })?;
+        authors.retain(|author| *author != Author::default());
+        Ok(authors)
+    }
+
+    /// Parses the value of any key as a version
+    pub fn get_version(&self, key: &str) -> Result<Version> {
+        let value = self.required(key)?;
+        Version::parse(value).map_err(|source| Error::Version {
+            key: key.to_owned(),
+            value: value.to_owned(),
+            source,
+        })
+    }
+
+    /// Parses the value of any key as a date in [`DATE_FORMAT`]
+    pub fn get_date(&self, key: &str) -> Result<NaiveDate> {
+        let value = self.required(key)?;
+        NaiveDate::parse_from_str(value, DATE_FORMAT).map_err(|source| Error::Date {
+            key: key.to_owned(),
+            value: value.to_owned(),
+            source,
+        })
+    }
+
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.insert(NAME.to_owned(), name.into());
+    }
+
+    pub fn set_description(&mut self, description: impl Into<String>) {
+        self.insert(DESCRIPTION.to_owned(), description.into());
+    }
+
+    pub fn set_authors<T: Into<Author>>(&mut self, authors: impl IntoIterator<Item = T>) {
+        let authors = authors.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.insert(AUTHORS.to_owned(), Author::to_value(&authors));
+    }
+
+    pub fn set_version(&mut self, version: &Version) {
+        self.insert_version(VERSION, version);
+    }
+
+    pub fn set_date(&mut self, date: NaiveDate) {
+        self.insert_date(DATE, date);
+    }
+
+    pub fn insert_version(&mut self, key: &str, version: &Version) {
+        self.insert(key.to_owned(), version.to_string());
+    }
+
+    pub fn insert_date(&mut self, key: &str, date: NaiveDate) {
+        self.insert(key.to_owned(), date.format(DATE_FORMAT).to_string());
+    }
+
+    /// Recorded operations, oldest first
+    pub fn provenance(&self) -> impl Iterator<Item = &str> {
+        self.get(PROVENANCE)
+            .into_iter()
+            .flat_map(|provenance| provenance.lines())
+    }
+
+    /// Records an operation in the provenance, prefixed with today's date,
+    /// and updates the date
+    pub fn record(&mut self, operation: &str) {
+        self.record_at(operation, Local::now().date_naive());
+    }
+
+    /// Records an operation in the provenance, prefixed with the date, and
+    /// updates the date
+    ///
+    /// Line breaks in the operation are replaced with spaces, as each entry is
+    /// a line.
+    pub fn record_at(&mut self, operation: &str, date: NaiveDate) {
+        let operation = operation.lines().collect::<Vec<_>>().join(" ");
+        let entry = format!("{} {operation}", date.format(DATE_FORMAT));
+        match self.get_mut(PROVENANCE) {
+            Some(provenance) if !provenance.is_empty() => {
+                provenance.push('\n');
+                provenance.push_str(&entry);
             }
-            if let Some(date) = self.get(DATE)
-                && date != DEFAULT_DATE
-            {
-                write!(f, "{separator}{date}")?;
+            _ => {
+                self.insert(PROVENANCE.to_owned(), entry);
             }
-            Ok(())
-        })
+        }
+        self.set_date(date);
+    }
+
+    fn required(&self, key: &str) -> Result<&str> {
+        self.get(key)
+            .map(String::as_str)
+            .ok_or_else(|| Error::Missing {
+                key: key.to_owned(),
+            })
+    }
+
+    /// Formats with the default [`Format`] and the given separator
+    pub fn format(&self, separator: &str) -> impl Debug + Display {
+        self.display(Format::new().with_separator(separator))
+    }
+
+    /// Formats with the given [`Format`]
+    pub fn display(&self, format: Format) -> impl Debug + Display {
+        from_fn(move |f| format.write(self, f))
     }
 }
 
 impl Display for Metadata {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        if let Some(name) = self.get(NAME) {
-            write!(f, "{name}")?;
-        }
-        if let Some(version) = self.get(VERSION)
-            && version != DEFAULT_VERSION
-        {
-            write!(f, "[{}]", version.trim_start_matches(['0', '.']))?;
-        }
-        if let Some(date) = self.get(DATE)
-            && date != DEFAULT_DATE
-        {
-            write!(f, "{date}")?;
-        }
-        Ok(())
+        Format::new().write(self, f)
     }
 }
 
@@ -82,8 +218,17 @@ impl FromIterator<(String, String)> for Metadata {
     }
 }
 
+mod author;
+mod bump;
+mod diff;
 #[cfg(feature = "egui")]
 pub mod egui;
-// mod error;
+mod error;
+mod format;
+mod merge;
 #[cfg(feature = "polars")]
 pub mod polars;
+mod record;
+mod schema;
+mod stem;
+mod validator;
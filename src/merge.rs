@@ -0,0 +1,175 @@
+use crate::{AUTHORS, Author, DATE, DATE_FORMAT, Error, Metadata, Result, VERSION};
+use chrono::NaiveDate;
+use semver::Version;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Merger
+///
+/// Merges several [`Metadata`] with a policy per key for the keys whose values
+/// differ.
+#[derive(Clone)]
+pub struct Merger {
+    default: MergePolicy,
+    policies: BTreeMap<String, MergePolicy>,
+}
+
+impl Merger {
+    /// Merger where the first value wins, with the built-in policies: union of
+    /// authors, newest date and highest version
+    pub fn new() -> Self {
+        Self::empty()
+            .with_policy(AUTHORS, MergePolicy::Union)
+            .with_policy(DATE, MergePolicy::Newest)
+            .with_policy(VERSION, MergePolicy::Highest)
+    }
+
+    /// Merger where the first value wins for every key
+    pub fn empty() -> Self {
+        Self {
+            default: MergePolicy::First,
+            policies: BTreeMap::new(),
+        }
+    }
+
+    /// Policy for the keys without their own policy
+    pub fn with_default(self, default: MergePolicy) -> Self {
+        Self { default, ..self }
+    }
+
+    pub fn with_policy(mut self, key: impl Into<String>, policy: MergePolicy) -> Self {
+        self.policies.insert(key.into(), policy);
+        self
+    }
+
+    /// Merges the metadata in order, returning the conflicts
+    pub fn merge<'a>(
+        &self,
+        metadata: impl IntoIterator<Item = &'a Metadata>,
+    ) -> Result<(Metadata, Vec<Conflict>)> {
+        let mut values = BTreeMap::<&str, Vec<&str>>::new();
+        for metadata in metadata {
+            for (key, value) in metadata.iter() {
+                let values = values.entry(key.as_str()).or_default();
+                if !values.contains(&value.as_str()) {
+                    values.push(value);
+                }
+            }
+        }
+        let mut merged = Metadata::default();
+        let mut conflicts = Vec::new();
+        for (key, values) in values {
+            let value = if let [value] = &*values {
+                value.to_string()
+            } else {
+                let policy = self.policies.get(key).unwrap_or(&self.default);
+                let value = policy.resolve(key, &values)?;
+                conflicts.push(Conflict {
+                    key: key.to_owned(),
+                    values: values.iter().map(ToString::to_string).collect(),
+                });
+                value
+            };
+            merged.insert(key.to_owned(), value);
+        }
+        Ok((merged, conflicts))
+    }
+}
+
+impl Default for Merger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Custom resolver of a key and its conflicting values
+pub type Resolver = dyn Fn(&str, &[&str]) -> Result<String> + Send + Sync;
+
+/// Merge policy
+#[derive(Clone)]
+pub enum MergePolicy {
+    /// First value wins
+    First,
+    /// Last value wins
+    Last,
+    /// Conflicting values are an error
+    Error,
+    /// Union of the authors, by name, in order of appearance
+    Union,
+    /// Newest date in [`DATE_FORMAT`]
+    Newest,
+    /// Highest semver version
+    Highest,
+    /// Custom resolver of the key and its conflicting values
+    Custom(Arc<Resolver>),
+}
+
+impl MergePolicy {
+    pub fn custom(f: impl Fn(&str, &[&str]) -> Result<String> + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(f))
+    }
+
+    /// Resolves at least two different values
+    fn resolve(&self, key: &str, values: &[&str]) -> Result<String> {
+        Ok(match self {
+            Self::First => values[0].to_owned(),
+            Self::Last => values[values.len() - 1].to_owned(),
+            Self::Error => {
+                return Err(Error::Conflict {
+                    key: key.to_owned(),
+                    values: values.iter().map(ToString::to_string).collect(),
+                });
+            }
+            Self::Union => {
+                let mut authors = Vec::<Author>::new();
+                for &value in values {
+                    let parsed = Author::from_value(value).map_err(|_| Error::Authors {
+                        key: key.to_owned(),
+                        value: value.to_owned(),
+                    })?;
+                    for author in parsed {
+                        if author != Author::default()
+                            && !authors.iter().any(|other| other.name == author.name)
+                        {
+                            authors.push(author);
+                        }
+                    }
+                }
+                Author::to_value(&authors)
+            }
+            Self::Newest => {
+                let mut newest = None;
+                for &value in values {
+                    let date = NaiveDate::parse_from_str(value, DATE_FORMAT).map_err(|source| {
+                        Error::Date {
+                            key: key.to_owned(),
+                            value: value.to_owned(),
+                            source,
+                        }
+                    })?;
+                    newest = newest.max(Some(date));
+                }
+                newest.unwrap_or_default().format(DATE_FORMAT).to_string()
+            }
+            Self::Highest => {
+                let mut highest = None;
+                for &value in values {
+                    let version = Version::parse(value).map_err(|source| Error::Version {
+                        key: key.to_owned(),
+                        value: value.to_owned(),
+                        source,
+                    })?;
+                    highest = highest.max(Some(version));
+                }
+                highest.unwrap_or_else(|| Version::new(0, 0, 0)).to_string()
+            }
+            Self::Custom(f) => f(key, values)?,
+        })
+    }
+}
+
+/// Key with different values, in order of appearance
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Conflict {
+    pub key: String,
+    pub values: Vec<String>,
+}
@@ -1,16 +1,236 @@
-use crate::Metadata;
+pub use self::{
+    ipc::{IpcReaderExt, IpcWriterExt},
+    lazy::MetaLazyFrame,
+};
+
+use crate::{Error, Metadata, Result};
 use polars::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    collections::BTreeMap,
+    path::Path,
+};
 
 /// MetaDataFrame
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct MetaDataFrame<M = Metadata, D = DataFrame> {
     pub meta: M,
     pub data: D,
+    /// Column metadata, keyed by column name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub columns: BTreeMap<String, Metadata>,
 }
 
 impl<M, D> MetaDataFrame<M, D> {
     pub const fn new(meta: M, data: D) -> Self {
-        Self { meta, data }
+        Self {
+            meta,
+            data,
+            columns: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_columns(self, columns: BTreeMap<String, Metadata>) -> Self {
+        Self { columns, ..self }
+    }
+
+    /// Maps the data, keeping the metadata
+    pub fn map_data<T>(self, f: impl FnOnce(D) -> T) -> MetaDataFrame<M, T> {
+        MetaDataFrame {
+            meta: self.meta,
+            data: f(self.data),
+            columns: self.columns,
+        }
+    }
+
+    /// Maps the data with a fallible function, keeping the metadata
+    pub fn try_map_data<T, E: Into<Error>>(
+        self,
+        f: impl FnOnce(D) -> Result<T, E>,
+    ) -> Result<MetaDataFrame<M, T>> {
+        Ok(MetaDataFrame {
+            meta: self.meta,
+            data: f(self.data).map_err(Into::into)?,
+            columns: self.columns,
+        })
+    }
+
+    /// Metadata of the column
+    pub fn column(&self, name: &str) -> Option<&Metadata> {
+        self.columns.get(name)
+    }
+
+    /// Metadata of the column, inserted empty if absent
+    pub fn column_mut(&mut self, name: &str) -> &mut Metadata {
+        self.columns.entry(name.to_owned()).or_default()
+    }
+}
+
+impl<D> MetaDataFrame<Metadata, D> {
+    /// Records an operation in the provenance of the metadata and updates the
+    /// date
+    pub fn record(mut self, operation: &str) -> Self {
+        self.meta.record(operation);
+        self
     }
 }
+
+impl MetaDataFrame {
+    /// Reads a file in the format given by its extension
+    pub fn read_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        match Format::new(path)? {
+            Format::Ipc => Self::read_ipc_file(path),
+            Format::Parquet => Self::read_parquet_file(path),
+        }
+    }
+}
+
+impl<D: BorrowMut<DataFrame>> MetaDataFrame<Metadata, D> {
+    /// Writes a file in the format given by its extension
+    pub fn write_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        match Format::new(path)? {
+            Format::Ipc => self.write_ipc_file(path),
+            Format::Parquet => self.write_parquet_file(path),
+        }
+    }
+}
+
+impl<M, D: Borrow<DataFrame>> MetaDataFrame<M, D> {
+    /// Removes the metadata of columns that are no longer in the data
+    pub fn retain_columns(&mut self) {
+        let data = self.data.borrow();
+        self.columns
+            .retain(|name, _| data.get_column_index(name).is_some());
+    }
+
+    /// Arrow schema of the data, with the column metadata in the field
+    /// metadata
+    fn arrow_schema(&self) -> ArrowSchema {
+        arrow_schema(self.data.borrow().schema(), &self.columns)
+    }
+}
+
+impl<M, D: BorrowMut<DataFrame>> MetaDataFrame<M, D> {
+    /// Renames a column along with its metadata
+    pub fn rename(&mut self, column: &str, name: &str) -> Result<()> {
+        self.data.borrow_mut().rename(column, name.into())?;
+        if let Some(metadata) = self.columns.remove(column) {
+            self.columns.insert(name.to_owned(), metadata);
+        }
+        Ok(())
+    }
+
+    /// Drops a column along with its metadata
+    pub fn drop_in_place(&mut self, name: &str) -> Result<Column> {
+        let column = self.data.borrow_mut().drop_in_place(name)?;
+        self.columns.remove(name);
+        Ok(column)
+    }
+}
+
+/// Arrow schema with the column metadata in the field metadata
+fn arrow_schema(schema: &Schema, columns: &BTreeMap<String, Metadata>) -> ArrowSchema {
+    let mut schema = schema.to_arrow(CompatLevel::newest());
+    for (name, metadata) in columns {
+        if let Some(field) = schema.get_mut(name.as_str()) {
+            let mut merged = field.metadata.as_deref().cloned().unwrap_or_default();
+            merged.extend(BTreeMap::from(metadata.clone()));
+            field.metadata = Some(Arc::new(merged));
+        }
+    }
+    schema
+}
+
+/// Column metadata from the field metadata of an Arrow schema, without the
+/// keys of Arrow and Polars
+fn columns(schema: &ArrowSchema) -> BTreeMap<String, Metadata> {
+    schema
+        .iter_values()
+        .filter_map(|field| {
+            let metadata = field
+                .metadata
+                .as_deref()?
+                .iter()
+                .filter(|(key, _)| !key.starts_with("ARROW:") && !key.starts_with("_PL_"))
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<Metadata>();
+            (!metadata.is_empty()).then(|| (field.name.to_string(), metadata))
+        })
+        .collect()
+}
+
+/// Metadata, schema and row count of a file, read without the data
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetaSchema {
+    pub meta: Metadata,
+    pub schema: Schema,
+    pub columns: BTreeMap<String, Metadata>,
+    pub rows: usize,
+}
+
+impl MetaSchema {
+    /// Reads a file in the format given by its extension
+    pub fn read_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        match Format::new(path)? {
+            Format::Ipc => Metadata::from_ipc_file(path),
+            Format::Parquet => Metadata::from_parquet_file(path),
+        }
+    }
+}
+
+/// File format
+enum Format {
+    Ipc,
+    Parquet,
+}
+
+impl Format {
+    fn new(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+        match &*extension.to_lowercase() {
+            "arrow" | "feather" | "ipc" => Ok(Self::Ipc),
+            "parquet" => Ok(Self::Parquet),
+            _ => Err(Error::Format {
+                key: path.display().to_string(),
+                value: extension.to_owned(),
+            }),
+        }
+    }
+}
+
+impl From<&BTreeMap<PlSmallStr, PlSmallStr>> for Metadata {
+    fn from(value: &BTreeMap<PlSmallStr, PlSmallStr>) -> Self {
+        value
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+}
+
+impl From<BTreeMap<PlSmallStr, PlSmallStr>> for Metadata {
+    fn from(value: BTreeMap<PlSmallStr, PlSmallStr>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<Metadata> for BTreeMap<PlSmallStr, PlSmallStr> {
+    fn from(value: Metadata) -> Self {
+        value
+            .0
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect()
+    }
+}
+
+mod ipc;
+mod lazy;
+mod merge;
+mod parquet;
@@ -0,0 +1,252 @@
+use super::{MetaDataFrame, MetaSchema, columns};
+use crate::{Metadata, Result};
+use polars::{io::mmap::MmapBytesReader, prelude::*};
+use polars_arrow::io::ipc::{
+    format::ipc::{MessageRef, planus::ReadAsRoot},
+    read::{StreamReader, StreamState, get_row_count, read_file_metadata, read_stream_metadata},
+    write::{FileWriter, StreamWriter, WriteOptions},
+};
+use std::{
+    borrow::BorrowMut,
+    fs::File,
+    io::{self, Cursor, ErrorKind, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+/// Marker before the length of a message in an Arrow IPC stream
+const CONTINUATION: [u8; 4] = [0xff; 4];
+
+/// Extension methods for [`IpcReader`]
+pub trait IpcReaderExt {
+    fn metadata(&mut self) -> Result<Option<Metadata>>;
+}
+
+impl<R: MmapBytesReader> IpcReaderExt for IpcReader<R> {
+    fn metadata(&mut self) -> Result<Option<Metadata>> {
+        let Some(metadata) = self.custom_metadata()? else {
+            return Ok(None);
+        };
+        Ok(Some(Metadata::from(&*metadata)))
+    }
+}
+
+impl<R: Read> IpcReaderExt for IpcStreamReader<R> {
+    fn metadata(&mut self) -> Result<Option<Metadata>> {
+        let Some(metadata) = self.custom_metadata()? else {
+            return Ok(None);
+        };
+        Ok(Some(Metadata::from(&*metadata)))
+    }
+}
+
+/// Extension methods for [`IpcWriter`]
+pub trait IpcWriterExt {
+    fn metadata(&mut self, metadata: Metadata);
+}
+
+impl<W: Write> IpcWriterExt for IpcWriter<W> {
+    fn metadata(&mut self, metadata: Metadata) {
+        self.set_custom_schema_metadata(Arc::new(metadata.into()));
+    }
+}
+
+impl<W: Write> IpcWriterExt for IpcStreamWriter<W> {
+    fn metadata(&mut self, metadata: Metadata) {
+        self.set_custom_schema_metadata(Arc::new(metadata.into()));
+    }
+}
+
+impl Metadata {
+    /// Reads the footer and the record batch headers only, without the data
+    pub fn from_ipc(mut reader: impl Read + Seek) -> Result<MetaSchema> {
+        let metadata = read_file_metadata(&mut reader)?;
+        reader.rewind()?;
+        let rows = get_row_count(&mut reader)? as _;
+        Ok(MetaSchema {
+            meta: metadata
+                .custom_schema_metadata
+                .as_deref()
+                .map(Metadata::from)
+                .unwrap_or_default(),
+            schema: Schema::from_arrow_schema(&metadata.schema),
+            columns: columns(&metadata.schema),
+            rows,
+        })
+    }
+
+    pub fn from_ipc_file(path: impl AsRef<Path>) -> Result<MetaSchema> {
+        let file = File::open(path)?;
+        Self::from_ipc(file)
+    }
+}
+
+impl MetaDataFrame {
+    pub fn read_ipc(reader: impl MmapBytesReader) -> Result<Self> {
+        let mut reader = IpcReader::new(reader);
+        let meta = reader.metadata()?.unwrap_or_default();
+        let columns = columns(&*reader.schema()?);
+        let data = reader.finish()?;
+        Ok(Self {
+            meta,
+            data,
+            columns,
+        })
+    }
+
+    pub fn read_ipc_file(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::open(path)?;
+        Self::read_ipc(file)
+    }
+
+    /// Reads the stream with Arrow directly, since [`IpcStreamReader`] does
+    /// not expose the field metadata
+    ///
+    /// The stream is read one message at a time, up to the end of stream
+    /// marker, so the source does not have to be seekable or closed.
+    pub fn read_ipc_stream(reader: impl Read) -> Result<Self> {
+        let mut reader = MessageReader::new(reader);
+        let metadata = read_stream_metadata(&mut reader)?;
+        let meta = metadata
+            .custom_schema_metadata
+            .as_ref()
+            .map(Metadata::from)
+            .unwrap_or_default();
+        let columns = columns(&metadata.schema);
+        let mut data = DataFrame::empty_with_arrow_schema(&metadata.schema);
+        for state in StreamReader::new(reader, metadata, None) {
+            match state? {
+                StreamState::Some(batch) => {
+                    data.vstack_mut_owned(DataFrame::from(batch))?;
+                }
+                // The source ended without the end of stream marker
+                StreamState::Waiting => break,
+            }
+        }
+        data.rechunk_mut();
+        Ok(Self {
+            meta,
+            data,
+            columns,
+        })
+    }
+}
+
+impl<D: BorrowMut<DataFrame>> MetaDataFrame<Metadata, D> {
+    /// Writes with Arrow directly, since [`IpcWriter`] does not write field
+    /// metadata
+    pub fn write_ipc(&mut self, writer: impl Write) -> Result<()> {
+        let schema = Arc::new(self.arrow_schema());
+        let data = self.data.borrow_mut();
+        let mut writer = FileWriter::new(writer, schema, None, WriteOptions::default());
+        writer.set_custom_schema_metadata(Arc::new(self.meta.clone().into()));
+        writer.start()?;
+        data.align_chunks_par();
+        for batch in data.iter_chunks(CompatLevel::newest(), true) {
+            writer.write(&batch, None)?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+
+    pub fn write_ipc_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let file = File::create(path)?;
+        self.write_ipc(file)
+    }
+
+    pub fn write_ipc_stream(&mut self, writer: impl Write) -> Result<()> {
+        let schema = self.arrow_schema();
+        let data = self.data.borrow_mut();
+        let mut writer = StreamWriter::new(writer, WriteOptions::default());
+        writer.set_custom_schema_metadata(Arc::new(self.meta.clone().into()));
+        writer.start(&schema, None)?;
+        data.align_chunks_par();
+        for batch in data.iter_chunks(CompatLevel::newest(), true) {
+            writer.write(&batch, None)?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+/// Reader of an Arrow IPC stream that buffers the current message only
+///
+/// The Arrow stream reader seeks within the body of the current message, which
+/// the buffer allows without seeking the source.
+struct MessageReader<R> {
+    reader: R,
+    message: Cursor<Vec<u8>>,
+}
+
+impl<R: Read> MessageReader<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            message: Cursor::default(),
+        }
+    }
+
+    /// Buffers the next message, nothing at the end of the source
+    fn next_message(&mut self) -> io::Result<()> {
+        let mut message = Vec::new();
+        let mut prefix = [0; 4];
+        if !self.read_prefix(&mut prefix)? {
+            self.message = Cursor::default();
+            return Ok(());
+        }
+        message.extend(prefix);
+        if prefix == CONTINUATION {
+            self.reader.read_exact(&mut prefix)?;
+            message.extend(prefix);
+        }
+        let length = i32::from_le_bytes(prefix);
+        if length > 0 {
+            let start = message.len();
+            self.read_to(&mut message, length as _)?;
+            let body = MessageRef::read_as_root(&message[start..])
+                .and_then(|header| header.body_length())
+                .map_err(|error| io::Error::new(ErrorKind::InvalidData, error.to_string()))?;
+            self.read_to(&mut message, body as _)?;
+        }
+        self.message = Cursor::new(message);
+        Ok(())
+    }
+
+    /// Reads the length prefix, `false` at the end of the source
+    fn read_prefix(&mut self, prefix: &mut [u8; 4]) -> io::Result<bool> {
+        let mut read = 0;
+        while read < prefix.len() {
+            match self.reader.read(&mut prefix[read..]) {
+                Ok(0) if read == 0 => return Ok(false),
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(length) => read += length,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(true)
+    }
+
+    /// Appends exactly `length` bytes of the source
+    fn read_to(&mut self, message: &mut Vec<u8>, length: u64) -> io::Result<()> {
+        let read = (&mut self.reader).take(length).read_to_end(message)?;
+        if read as u64 != length {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for MessageReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.message.position() >= self.message.get_ref().len() as u64 {
+            self.next_message()?;
+        }
+        self.message.read(buf)
+    }
+}
+
+impl<R> Seek for MessageReader<R> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        self.message.seek(position)
+    }
+}
@@ -0,0 +1,85 @@
+use super::{MetaDataFrame, MetaSchema, parquet::key_value_metadata};
+use crate::{Metadata, Result};
+use polars::prelude::*;
+use std::path::Path;
+
+/// MetaDataFrame with a lazy frame
+pub type MetaLazyFrame = MetaDataFrame<Metadata, LazyFrame>;
+
+impl<M> MetaDataFrame<M, DataFrame> {
+    pub fn lazy(self) -> MetaDataFrame<M, LazyFrame> {
+        MetaDataFrame {
+            meta: self.meta,
+            data: self.data.lazy(),
+            columns: self.columns,
+        }
+    }
+}
+
+impl MetaLazyFrame {
+    /// Reads the metadata from the footer and scans the data lazily
+    pub fn scan_parquet(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let MetaSchema { meta, columns, .. } = Metadata::from_parquet_file(path)?;
+        let data = LazyFrame::scan_parquet(PlPath::Local(path.into()), Default::default())?;
+        Ok(Self {
+            meta,
+            data,
+            columns,
+        })
+    }
+
+    /// Reads the metadata from the footer and scans the data lazily
+    pub fn scan_ipc(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let MetaSchema { meta, columns, .. } = Metadata::from_ipc_file(path)?;
+        let data = LazyFrame::scan_ipc(
+            PlPath::Local(path.into()),
+            Default::default(),
+            Default::default(),
+        )?;
+        Ok(Self {
+            meta,
+            data,
+            columns,
+        })
+    }
+
+    /// Collects the data, dropping the metadata of columns no longer there
+    pub fn collect(self) -> Result<MetaDataFrame> {
+        let mut frame = MetaDataFrame {
+            meta: self.meta,
+            data: self.data.collect()?,
+            columns: self.columns,
+        };
+        frame.retain_columns();
+        Ok(frame)
+    }
+
+    /// Streams the data into a Parquet file, with the metadata and the column
+    /// metadata in the footer
+    pub fn sink_parquet(mut self, path: impl AsRef<Path>) -> Result<()> {
+        let schema = self.data.collect_schema()?;
+        let options = ParquetWriteOptions {
+            key_value_metadata: Some(key_value_metadata(&self.meta, &schema, &self.columns)),
+            ..Default::default()
+        };
+        self.data
+            .sink_parquet(
+                SinkTarget::Path(PlPath::Local(path.as_ref().into())),
+                options,
+                None,
+                SinkOptions::default(),
+            )?
+            .collect()?;
+        Ok(())
+    }
+
+    /// Collects the data and writes it into an IPC file
+    ///
+    /// The IPC sink of Polars does not write custom schema metadata, so the
+    /// data is not streamed.
+    pub fn sink_ipc(self, path: impl AsRef<Path>) -> Result<()> {
+        self.collect()?.write_ipc_file(path)
+    }
+}
@@ -0,0 +1,109 @@
+use super::MetaDataFrame;
+use crate::{Conflict, Merger, Metadata, Result};
+use polars::prelude::*;
+use std::collections::BTreeMap;
+
+impl MetaDataFrame {
+    /// Stacks the frames vertically, merging their metadata and column
+    /// metadata in order
+    ///
+    /// The conflicts of the column metadata are reported with the key
+    /// prefixed by the column name and a dot.
+    pub fn concat(
+        frames: impl IntoIterator<Item = MetaDataFrame>,
+        merger: &Merger,
+    ) -> Result<(Self, Vec<Conflict>)> {
+        let mut metas = Vec::new();
+        let mut columns = Vec::new();
+        let mut data = None::<DataFrame>;
+        for frame in frames {
+            metas.push(frame.meta);
+            columns.push(frame.columns);
+            match &mut data {
+                Some(data) => {
+                    data.vstack_mut_owned(frame.data)?;
+                }
+                None => data = Some(frame.data),
+            }
+        }
+        let mut data = data.unwrap_or_default();
+        data.rechunk_mut();
+        let (meta, mut conflicts) = merger.merge(&metas)?;
+        let columns = merge_columns(&columns, merger, &mut conflicts)?;
+        Ok((
+            Self {
+                meta,
+                data,
+                columns,
+            },
+            conflicts,
+        ))
+    }
+
+    /// Joins the frames, merging their metadata and column metadata
+    ///
+    /// The column metadata of the right frame follows its columns renamed
+    /// with the suffix of the join arguments.
+    pub fn join(
+        self,
+        other: MetaDataFrame,
+        left_on: &[&str],
+        right_on: &[&str],
+        args: JoinArgs,
+        merger: &Merger,
+    ) -> Result<(Self, Vec<Conflict>)> {
+        let (meta, mut conflicts) = merger.merge([&self.meta, &other.meta])?;
+        let right = other
+            .columns
+            .into_iter()
+            .map(|(name, metadata)| {
+                if self.data.get_column_index(&name).is_some() && !right_on.contains(&&*name) {
+                    (format!("{name}{}", args.suffix()), metadata)
+                } else {
+                    (name, metadata)
+                }
+            })
+            .collect();
+        let columns = merge_columns(&[self.columns, right], merger, &mut conflicts)?;
+        let data = self
+            .data
+            .lazy()
+            .join(
+                other.data.lazy(),
+                left_on.iter().map(|&name| col(name)).collect::<Vec<_>>(),
+                right_on.iter().map(|&name| col(name)).collect::<Vec<_>>(),
+                args,
+            )
+            .collect()?;
+        let mut frame = Self {
+            meta,
+            data,
+            columns,
+        };
+        frame.retain_columns();
+        Ok((frame, conflicts))
+    }
+}
+
+fn merge_columns(
+    columns: &[BTreeMap<String, Metadata>],
+    merger: &Merger,
+    conflicts: &mut Vec<Conflict>,
+) -> Result<BTreeMap<String, Metadata>> {
+    let mut metadata = BTreeMap::<&str, Vec<&Metadata>>::new();
+    for columns in columns {
+        for (name, column) in columns {
+            metadata.entry(name.as_str()).or_default().push(column);
+        }
+    }
+    let mut merged = BTreeMap::new();
+    for (name, metadata) in metadata {
+        let (column, column_conflicts) = merger.merge(metadata)?;
+        conflicts.extend(column_conflicts.into_iter().map(|conflict| Conflict {
+            key: format!("{name}.{}", conflict.key),
+            ..conflict
+        }));
+        merged.insert(name.to_owned(), column);
+    }
+    Ok(merged)
+}
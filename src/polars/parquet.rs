@@ -0,0 +1,238 @@
+use super::{MetaDataFrame, MetaSchema, arrow_schema, columns};
+use crate::{Metadata, Result};
+use polars::{
+    io::{mmap::MmapBytesReader, parquet::write::get_column_write_options},
+    prelude::*,
+};
+use polars_parquet::{
+    parquet::metadata::{KeyValue, ThriftFileMetadata},
+    write::schema_to_metadata_key,
+};
+use polars_parquet_format::thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol};
+use std::{
+    borrow::BorrowMut,
+    collections::BTreeMap,
+    fs::{File, remove_file, rename},
+    io::{self, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
+/// Key under which the Arrow schema is stored in the Parquet footer
+const ARROW_SCHEMA: &str = "ARROW:schema";
+
+/// Magic bytes at the start and the end of a Parquet file
+const MAGIC: &[u8; 4] = b"PAR1";
+
+impl Metadata {
+    /// Reads the footer only, without the data
+    pub fn from_parquet(reader: impl MmapBytesReader) -> Result<MetaSchema> {
+        footer(&mut ParquetReader::new(reader))
+    }
+
+    pub fn from_parquet_file(path: impl AsRef<Path>) -> Result<MetaSchema> {
+        let file = File::open(path)?;
+        Self::from_parquet(file)
+    }
+
+    /// Replaces the key-value metadata in the footer of a Parquet file
+    ///
+    /// The row groups are copied byte for byte into a temporary file next to
+    /// the original, followed by the new footer, and the temporary file is
+    /// then renamed over the original. The temporary file gets the
+    /// permissions of the original, and the directory is synced after the
+    /// rename. A symbolic link is resolved first, so the file it points to is
+    /// replaced and not the link.
+    ///
+    /// An `ARROW:schema` key in the metadata is skipped, since the one of the
+    /// original footer describes the data.
+    pub fn write_parquet_footer(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = &path.as_ref().canonicalize()?;
+        let (temporary, target) = temporary_file(path)?;
+        let result = self.copy_parquet(path, target).and_then(|()| {
+            rename(&temporary, path)?;
+            sync_parent(path)?;
+            Ok(())
+        });
+        if result.is_err() {
+            let _ = remove_file(&temporary);
+        }
+        result
+    }
+
+    fn copy_parquet(&self, source: &Path, target: File) -> Result<()> {
+        let mut source = File::open(source)?;
+        let length = source.seek(SeekFrom::End(0))?;
+        if length < 12 {
+            return Err(invalid_data("file too small to be Parquet").into());
+        }
+        let mut footer = [0; 8];
+        source.seek(SeekFrom::End(-8))?;
+        source.read_exact(&mut footer)?;
+        if footer[4..] != *MAGIC {
+            return Err(invalid_data("missing Parquet magic bytes").into());
+        }
+        let metadata_length = u32::from_le_bytes([footer[0], footer[1], footer[2], footer[3]]);
+        let Some(start) = (length - 8).checked_sub(metadata_length as _) else {
+            return Err(invalid_data("Parquet footer longer than the file").into());
+        };
+        source.seek(SeekFrom::Start(start))?;
+        let mut bytes = Vec::with_capacity(metadata_length as _);
+        (&mut source)
+            .take(metadata_length as _)
+            .read_to_end(&mut bytes)?;
+        let mut protocol = TCompactInputProtocol::new(bytes.as_slice(), bytes.len() * 2 + 1024);
+        let mut metadata = ThriftFileMetadata::read_from_in_protocol(&mut protocol)
+            .map_err(|error| invalid_data(error.to_string()))?;
+        // The Arrow schema describes the data, which is left unchanged
+        let mut key_values = metadata
+            .key_value_metadata
+            .take()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|key_value| key_value.key == ARROW_SCHEMA)
+            .collect::<Vec<_>>();
+        key_values.extend(
+            self.iter()
+                .filter(|(key, _)| *key != ARROW_SCHEMA)
+                .map(|(key, value)| KeyValue::new(key.clone(), Some(value.clone()))),
+        );
+        metadata.key_value_metadata = Some(key_values);
+
+        target.set_permissions(source.metadata()?.permissions())?;
+        let mut target = BufWriter::new(target);
+        source.seek(SeekFrom::Start(0))?;
+        io::copy(&mut (&mut source).take(start), &mut target)?;
+        let metadata_length = metadata
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut target))
+            .map_err(|error| invalid_data(error.to_string()))?;
+        target.write_all(&(metadata_length as u32).to_le_bytes())?;
+        target.write_all(MAGIC)?;
+        target
+            .into_inner()
+            .map_err(|error| error.into_error())?
+            .sync_all()?;
+        Ok(())
+    }
+}
+
+impl MetaDataFrame {
+    pub fn read_parquet(reader: impl MmapBytesReader) -> Result<Self> {
+        let mut reader = ParquetReader::new(reader).set_rechunk(true);
+        let MetaSchema { meta, columns, .. } = footer(&mut reader)?;
+        let data = reader.finish()?;
+        Ok(Self {
+            meta,
+            data,
+            columns,
+        })
+    }
+
+    pub fn read_parquet_file(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::open(path)?;
+        Self::read_parquet(file)
+    }
+}
+
+/// Temporary file next to the path, created exclusively under a unique name
+fn temporary_file(path: &Path) -> io::Result<(PathBuf, File)> {
+    let file_name = path.file_name().unwrap_or_default();
+    let mut index = 0;
+    loop {
+        let mut temporary = file_name.to_owned();
+        temporary.push(format!(".{}.{index}.tmp", process::id()));
+        let temporary = path.with_file_name(temporary);
+        match File::options()
+            .write(true)
+            .create_new(true)
+            .open(&temporary)
+        {
+            Ok(file) => return Ok((temporary, file)),
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => index += 1,
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// Syncs the directory of the path, so a rename into it is durable
+#[cfg(unix)]
+fn sync_parent(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => File::open(parent)?.sync_all(),
+        _ => File::open(".")?.sync_all(),
+    }
+}
+
+/// Directories can not be opened as files on other platforms
+#[cfg(not(unix))]
+fn sync_parent(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+fn footer<R: MmapBytesReader>(reader: &mut ParquetReader<R>) -> Result<MetaSchema> {
+    let meta = reader
+        .get_metadata()?
+        .key_value_metadata()
+        .as_ref()
+        .map(|key_values| {
+            key_values
+                .iter()
+                .filter(|key_value| key_value.key != ARROW_SCHEMA)
+                .filter_map(|key_value| Some((key_value.key.clone(), key_value.value.clone()?)))
+                .collect()
+        })
+        .unwrap_or_default();
+    let rows = reader.num_rows()?;
+    let schema = reader.schema()?;
+    Ok(MetaSchema {
+        meta,
+        schema: Schema::from_arrow_schema(&schema),
+        columns: columns(&schema),
+        rows,
+    })
+}
+
+impl<D: BorrowMut<DataFrame>> MetaDataFrame<Metadata, D> {
+    pub fn write_parquet(&mut self, writer: impl Write) -> Result<()> {
+        let key_value_metadata =
+            key_value_metadata(&self.meta, self.data.borrow().schema(), &self.columns);
+        ParquetWriter::new(writer)
+            .with_key_value_metadata(Some(key_value_metadata))
+            .finish(self.data.borrow_mut())?;
+        Ok(())
+    }
+
+    pub fn write_parquet_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let file = File::create(path)?;
+        self.write_parquet(file)
+    }
+}
+
+/// Key-value metadata of the footer
+///
+/// Polars keeps an Arrow schema given in the key-value metadata, which is the
+/// only way to write field metadata through its writers. An `ARROW:schema` key
+/// in the metadata is skipped, so the footer has one that describes the data.
+pub(super) fn key_value_metadata(
+    meta: &Metadata,
+    schema: &Schema,
+    columns: &BTreeMap<String, Metadata>,
+) -> KeyValueMetadata {
+    let mut key_values = meta
+        .iter()
+        .filter(|(key, _)| *key != ARROW_SCHEMA)
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Vec<_>>();
+    if !columns.is_empty() {
+        let schema = arrow_schema(schema, columns);
+        let key_value = schema_to_metadata_key(&schema, &get_column_write_options(&schema, &[]));
+        if let Some(value) = key_value.value {
+            key_values.push((key_value.key, value));
+        }
+    }
+    KeyValueMetadata::from_static(key_values)
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.into())
+}
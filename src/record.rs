@@ -0,0 +1,104 @@
+use crate::{AUTHORS, Author, DATE, DESCRIPTION, Error, Metadata, NAME, Result, VERSION};
+use chrono::NaiveDate;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Well-known keys of the typed fields
+const KEYS: [&str; 5] = [NAME, DESCRIPTION, AUTHORS, VERSION, DATE];
+
+/// Metadata record
+///
+/// Typed view of [`Metadata`]. Keys that are not well-known are kept in
+/// `extra`. Well-known values that are not in their canonical form are kept in
+/// `raw` and written back as is while the typed field still holds the value
+/// they were parsed into, so an unchanged record converts back to the same
+/// [`Metadata`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct MetadataRecord {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub authors: Vec<Author>,
+    pub version: Option<Version>,
+    pub date: Option<NaiveDate>,
+    pub extra: BTreeMap<String, String>,
+    /// Original well-known values that are not in their canonical form
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub raw: BTreeMap<String, String>,
+}
+
+impl TryFrom<&Metadata> for MetadataRecord {
+    type Error = Error;
+
+    fn try_from(value: &Metadata) -> Result<Self> {
+        let mut extra = value.0.clone();
+        for key in KEYS {
+            extra.remove(key);
+        }
+        let record = Self {
+            name: value.name().map(ToOwned::to_owned),
+            description: value.description().map(ToOwned::to_owned),
+            authors: value.structured_authors()?,
+            version: value.get(VERSION).map(|_| value.version()).transpose()?,
+            date: value.get(DATE).map(|_| value.date()).transpose()?,
+            extra,
+            raw: BTreeMap::new(),
+        };
+        let canonical = Metadata::from(record.clone());
+        let raw = KEYS
+            .into_iter()
+            .filter_map(|key| {
+                let value = value.get(key)?;
+                (canonical.get(key) != Some(value)).then(|| (key.to_owned(), value.clone()))
+            })
+            .collect();
+        Ok(Self { raw, ..record })
+    }
+}
+
+impl TryFrom<Metadata> for MetadataRecord {
+    type Error = Error;
+
+    fn try_from(value: Metadata) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+impl From<MetadataRecord> for Metadata {
+    fn from(value: MetadataRecord) -> Self {
+        let mut metadata = Metadata(value.extra);
+        if let Some(name) = value.name {
+            metadata.set_name(name);
+        }
+        if let Some(description) = value.description {
+            metadata.set_description(description);
+        }
+        if !value.authors.is_empty() {
+            metadata.set_authors(value.authors);
+        }
+        if let Some(version) = &value.version {
+            metadata.set_version(version);
+        }
+        if let Some(date) = value.date {
+            metadata.set_date(date);
+        }
+        for (key, raw) in value.raw {
+            if KEYS.contains(&&*key) && canonical(&key, &raw) == metadata.get(&key).cloned() {
+                metadata.insert(key, raw);
+            }
+        }
+        metadata
+    }
+}
+
+/// Canonical form of a well-known value, `None` if it is not written back
+fn canonical(key: &str, value: &str) -> Option<String> {
+    let metadata = Metadata::from_iter([(key.to_owned(), value.to_owned())]);
+    let record = MetadataRecord::try_from(&metadata).ok()?;
+    Metadata::from(MetadataRecord {
+        raw: BTreeMap::new(),
+        ..record
+    })
+    .get(key)
+    .cloned()
+}
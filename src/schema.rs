@@ -0,0 +1,212 @@
+use crate::{
+    AUTHORS, Author, DATE, DESCRIPTION, Diagnostic, Error, Metadata, NAME, Result, Rule, Severity,
+    VERSION, Validator,
+};
+use serde::{Deserialize, Serialize};
+#[cfg(any(feature = "json", feature = "toml"))]
+use std::fs::read_to_string;
+use std::path::Path;
+
+/// Schema
+///
+/// Describes the keys expected in a [`Metadata`]. Can be loaded from JSON or
+/// TOML with the `json` or `toml` feature:
+///
+/// ```toml
+/// [[fields]]
+/// key = "Crop"
+/// type = "string"
+/// required = true
+/// description = "Crop species"
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Schema {
+    #[serde(default)]
+    pub fields: Vec<Field>,
+}
+
+impl Schema {
+    /// Schema of the well-known keys
+    pub fn standard() -> Self {
+        Self {
+            fields: vec![
+                Field::new(NAME, ValueType::String).with_required(true),
+                Field::new(DESCRIPTION, ValueType::Text),
+                Field::new(AUTHORS, ValueType::Authors),
+                Field::new(VERSION, ValueType::Semver),
+                Field::new(DATE, ValueType::Date),
+            ],
+        }
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Loads a `.json` or `.toml` file, if its feature is enabled
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+        match &*extension.to_lowercase() {
+            #[cfg(feature = "json")]
+            "json" => Self::from_json(&read_to_string(path)?),
+            #[cfg(feature = "toml")]
+            "toml" => Self::from_toml(&read_to_string(path)?),
+            _ => Err(Error::Format {
+                key: path.display().to_string(),
+                value: extension.to_owned(),
+            }),
+        }
+    }
+
+    pub fn field(&self, key: &str) -> Option<&Field> {
+        self.fields.iter().find(|field| field.key == key)
+    }
+
+    /// Inserts the defaults of missing keys
+    pub fn apply_defaults(&self, metadata: &mut Metadata) {
+        for field in &self.fields {
+            if let Some(default) = &field.default
+                && !metadata.contains_key(&field.key)
+            {
+                metadata.insert(field.key.clone(), default.clone());
+            }
+        }
+    }
+
+    pub fn validator(&self) -> Validator {
+        let mut validator = Validator::empty();
+        for field in &self.fields {
+            if field.required {
+                validator = validator.with_rule(&*field.key, Rule::Required, Severity::Error);
+            }
+            if let Some(rule) = field.kind.rule() {
+                validator = validator.with_rule(&*field.key, rule, Severity::Error);
+            }
+        }
+        validator
+    }
+
+    pub fn validate(&self, metadata: &Metadata) -> Vec<Diagnostic> {
+        self.validator().validate(metadata)
+    }
+}
+
+impl From<&Schema> for Validator {
+    fn from(value: &Schema) -> Self {
+        value.validator()
+    }
+}
+
+/// Field
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Field {
+    pub key: String,
+    #[serde(default, rename = "type")]
+    pub kind: ValueType,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl Field {
+    pub fn new(key: impl Into<String>, kind: ValueType) -> Self {
+        Self {
+            key: key.into(),
+            kind,
+            required: false,
+            default: None,
+            description: None,
+        }
+    }
+
+    pub fn with_required(self, required: bool) -> Self {
+        Self { required, ..self }
+    }
+
+    pub fn with_default(self, default: impl Into<String>) -> Self {
+        Self {
+            default: Some(default.into()),
+            ..self
+        }
+    }
+
+    pub fn with_description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+}
+
+/// Value type
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValueType {
+    /// Single line text
+    #[default]
+    String,
+    /// Multiline text
+    Text,
+    /// Comma separated list
+    List,
+    /// [`Author`] list
+    Authors,
+    /// Semantic version
+    Semver,
+    /// Date in [`DATE_FORMAT`](crate::DATE_FORMAT)
+    Date,
+    /// Floating point number
+    Number,
+    /// URL with a scheme
+    Url,
+}
+
+impl ValueType {
+    fn rule(&self) -> Option<Rule> {
+        match self {
+            Self::String | Self::Text | Self::List => None,
+            Self::Authors => Some(Rule::custom(|value| {
+                Author::from_value(value)
+                    .map(drop)
+                    .map_err(|error| format!(r#""{value}" is not an authors list ({error})"#))
+            })),
+            Self::Semver => Some(Rule::Version),
+            Self::Date => Some(Rule::Date),
+            Self::Number => Some(Rule::custom(|value| {
+                value
+                    .trim()
+                    .parse::<f64>()
+                    .map(drop)
+                    .map_err(|error| format!(r#""{value}" is not a number ({error})"#))
+            })),
+            Self::Url => Some(Rule::custom(|value| {
+                let valid = value.split_once("://").is_some_and(|(scheme, rest)| {
+                    scheme.starts_with(|character: char| character.is_ascii_alphabetic())
+                        && scheme.chars().all(|character| {
+                            character.is_ascii_alphanumeric() || "+-.".contains(character)
+                        })
+                        && !rest.is_empty()
+                        && !rest.contains(char::is_whitespace)
+                });
+                if valid {
+                    Ok(())
+                } else {
+                    Err(format!(r#""{value}" is not a URL"#))
+                }
+            })),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{DATE, DATE_FORMAT, DEFAULT_DATE, DEFAULT_VERSION, Metadata, NAME, VERSION};
+use chrono::NaiveDate;
+use semver::Version;
+
+/// Maximum length of a file stem in bytes
+pub const FILE_STEM_LENGTH: usize = 100;
+
+const SEPARATOR: char = '_';
+const UNTITLED: &str = "untitled";
+/// Stems reserved on Windows, whatever the extension
+const RESERVED: [&str; 22] = [
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+    "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
+impl Metadata {
+    /// File stem
+    ///
+    /// Builds `name_vversion_date` from the slugified name, the version and the
+    /// date. The name always comes first, `untitled` if it is missing, so it is
+    /// never mistaken for a version or a date. A name reserved on Windows gets
+    /// a `-file` suffix. Missing, default and invalid versions and dates are
+    /// skipped, and the name is shortened to keep the stem within
+    /// [`FILE_STEM_LENGTH`] bytes, or the version is skipped if it is too long.
+    ///
+    /// The stem keeps the dots of the version, so the extension must be
+    /// appended, as [`Metadata::file_name`] does, and not set with
+    /// [`Path::with_extension`](std::path::Path::with_extension), which would
+    /// replace the end of the version.
+    pub fn file_stem(&self) -> String {
+        let mut version = String::new();
+        if let Some(value) = self.get(VERSION)
+            && value != DEFAULT_VERSION
+            && let Ok(value) = Version::parse(value)
+        {
+            version.push(SEPARATOR);
+            version.push('v');
+            version.push_str(&value.to_string());
+        }
+        let mut date = String::new();
+        if let Some(value) = self.get(DATE)
+            && value != DEFAULT_DATE
+            && let Ok(value) = NaiveDate::parse_from_str(value, DATE_FORMAT)
+        {
+            date.push(SEPARATOR);
+            date.push_str(&value.format(DATE_FORMAT).to_string());
+        }
+        let stem = self.stem(&[&*version, &*date].concat());
+        if stem.len() <= FILE_STEM_LENGTH {
+            return stem;
+        }
+        self.stem(&date)
+    }
+
+    /// File name of the stem and the extension
+    pub fn file_name(&self, extension: &str) -> String {
+        format!("{}.{extension}", self.file_stem())
+    }
+
+    /// Parses a stem built by [`Metadata::file_stem`]
+    ///
+    /// The name can not be restored exactly, so it is set to its slug.
+    pub fn from_file_stem(stem: &str) -> Self {
+        let mut metadata = Self::default();
+        let mut parts = stem.split(SEPARATOR).peekable();
+        if let Some(name) = parts.next()
+            && !name.is_empty()
+            && name != UNTITLED
+        {
+            metadata.set_name(name.to_owned());
+        }
+        if let Some(version) = parts.peek()
+            && let Some(version) = version.strip_prefix('v')
+            && let Ok(version) = Version::parse(version)
+        {
+            metadata.set_version(&version);
+            parts.next();
+        }
+        if let Some(date) = parts.next()
+            && let Ok(date) = NaiveDate::parse_from_str(date, DATE_FORMAT)
+        {
+            metadata.set_date(date);
+        }
+        metadata
+    }
+
+    /// Slugified name followed by the suffix
+    fn stem(&self, suffix: &str) -> String {
+        let length = FILE_STEM_LENGTH.saturating_sub(suffix.len());
+        let mut name = slugify(self.get(NAME).map_or("", String::as_str), length);
+        if name.is_empty() {
+            name.push_str(UNTITLED);
+        } else if RESERVED.contains(&&*name) {
+            name.push_str("-file");
+        }
+        name.push_str(suffix);
+        name
+    }
+}
+
+/// Lower cases letters and digits and replaces runs of anything else with a
+/// single `-`, so the result is safe on common filesystems
+fn slugify(value: &str, length: usize) -> String {
+    let mut slug = String::new();
+    for character in value.chars().flat_map(char::to_lowercase) {
+        if character.is_alphanumeric() {
+            slug.push(character);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let end = slug
+        .char_indices()
+        .map(|(index, character)| index + character.len_utf8())
+        .take_while(|&end| end <= length)
+        .last()
+        .unwrap_or_default();
+    slug.truncate(end);
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
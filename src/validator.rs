@@ -0,0 +1,154 @@
+use crate::{DATE, DATE_FORMAT, Metadata, NAME, Result, VERSION};
+use chrono::NaiveDate;
+#[cfg(feature = "regex")]
+use regex::Regex;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+};
+
+/// Validator
+///
+/// Runs rules registered per key over a [`Metadata`].
+#[derive(Clone)]
+pub struct Validator {
+    checks: Vec<Check>,
+}
+
+impl Validator {
+    /// Validator with the built-in rules: non empty name, semver version and
+    /// date in [`DATE_FORMAT`]
+    pub fn new() -> Self {
+        Self::empty()
+            .with_rule(NAME, Rule::Required, Severity::Error)
+            .with_rule(NAME, Rule::NonEmpty, Severity::Error)
+            .with_rule(VERSION, Rule::Version, Severity::Error)
+            .with_rule(DATE, Rule::Date, Severity::Error)
+    }
+
+    /// Validator without rules
+    pub fn empty() -> Self {
+        Self { checks: Vec::new() }
+    }
+
+    pub fn with_rule(mut self, key: impl Into<String>, rule: Rule, severity: Severity) -> Self {
+        self.checks.push(Check {
+            key: key.into(),
+            rule,
+            severity,
+        });
+        self
+    }
+
+    pub fn validate(&self, metadata: &Metadata) -> Vec<Diagnostic> {
+        self.checks
+            .iter()
+            .filter_map(|check| {
+                let message = check
+                    .rule
+                    .check(metadata.get(&check.key).map(String::as_str))?;
+                Some(Diagnostic {
+                    key: check.key.clone(),
+                    severity: check.severity,
+                    message,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check
+#[derive(Clone)]
+struct Check {
+    key: String,
+    rule: Rule,
+    severity: Severity,
+}
+
+/// Custom check of a present value, returning the message on failure
+pub type CustomCheck = dyn Fn(&str) -> Result<(), String> + Send + Sync;
+
+/// Rule
+///
+/// Only [`Rule::Required`] fails on a missing key, the other rules check
+/// present values.
+#[derive(Clone)]
+pub enum Rule {
+    Required,
+    NonEmpty,
+    Version,
+    Date,
+    #[cfg(feature = "regex")]
+    Regex(Regex),
+    Allowed(Vec<String>),
+    Custom(Arc<CustomCheck>),
+}
+
+impl Rule {
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<Self> {
+        Ok(Self::Regex(Regex::new(pattern)?))
+    }
+
+    pub fn allowed<T: Into<String>>(values: impl IntoIterator<Item = T>) -> Self {
+        Self::Allowed(values.into_iter().map(Into::into).collect())
+    }
+
+    pub fn custom(f: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(f))
+    }
+
+    /// Returns a message if the value breaks the rule
+    fn check(&self, value: Option<&str>) -> Option<String> {
+        let Some(value) = value else {
+            return matches!(self, Self::Required).then(|| "is missing".to_owned());
+        };
+        match self {
+            Self::Required => None,
+            Self::NonEmpty => value.trim().is_empty().then(|| "is empty".to_owned()),
+            Self::Version => Version::parse(value)
+                .err()
+                .map(|error| format!(r#""{value}" is not a semantic version ({error})"#)),
+            Self::Date => NaiveDate::parse_from_str(value, DATE_FORMAT)
+                .err()
+                .map(|error| format!(r#""{value}" is not a {DATE_FORMAT} date ({error})"#)),
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => {
+                (!regex.is_match(value)).then(|| format!(r#""{value}" does not match "{regex}""#))
+            }
+            Self::Allowed(values) => (!values.iter().any(|allowed| allowed == value))
+                .then(|| format!(r#""{value}" is not one of {values:?}"#)),
+            Self::Custom(f) => f(value).err(),
+        }
+    }
+}
+
+/// Severity
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// Diagnostic
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Diagnostic {
+    pub key: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?} {}: {}", self.severity, self.key, self.message)
+    }
+}
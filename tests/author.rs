@@ -0,0 +1,63 @@
+use metadata::{AUTHORS, Author, Error, Metadata};
+
+#[test]
+fn legacy() {
+    let authors = Author::from_value("Kazakov, Sidorov,").unwrap();
+    assert_eq!(
+        authors,
+        [
+            Author::new("Kazakov"),
+            Author::new("Sidorov"),
+            Author::default()
+        ],
+    );
+    assert_eq!(Author::to_value(&authors), "Kazakov,Sidorov,");
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn structured() {
+    let authors = [
+        Author {
+            email: Some("smith@example.org".to_owned()),
+            orcid: Some("0000-0002-1825-0097".to_owned()),
+            ..Author::new("Smith, J.")
+        },
+        Author::new("Sidorov"),
+    ];
+    let value = Author::to_value(&authors);
+    assert!(value.starts_with('['));
+    assert_eq!(Author::from_value(&value).unwrap(), authors);
+
+    let mut metadata = Metadata::default();
+    metadata.set_authors(authors.clone());
+    assert_eq!(metadata.structured_authors().unwrap(), authors);
+    assert_eq!(metadata.authors(), ["Smith, J.", "Sidorov"]);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn malformed() {
+    let metadata = Metadata::from_iter([(AUTHORS.to_owned(), r#"[{"name": }]"#.to_owned())]);
+    assert!(matches!(
+        metadata.structured_authors(),
+        Err(Error::Authors { .. })
+    ));
+    assert!(metadata.authors().is_empty());
+    assert!(Metadata::default().structured_authors().unwrap().is_empty());
+}
+
+#[cfg(not(feature = "json"))]
+#[test]
+fn without_json() {
+    let author = Author {
+        email: Some("smith@example.org".to_owned()),
+        ..Author::new("Smith")
+    };
+    assert_eq!(Author::to_value(&[author]), "Smith");
+    let metadata = Metadata::from_iter([(AUTHORS.to_owned(), r#"[{"name": "Smith"}]"#.to_owned())]);
+    assert!(matches!(
+        metadata.structured_authors(),
+        Err(Error::Authors { .. })
+    ));
+}
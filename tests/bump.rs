@@ -0,0 +1,34 @@
+use metadata::Bump;
+use semver::Version;
+
+#[test]
+fn apply() {
+    let version = Version::parse("1.2.3-rc.1+build.5").unwrap();
+    assert_eq!(Bump::Major.apply(&version), Version::new(2, 0, 0));
+    assert_eq!(Bump::Minor.apply(&version), Version::new(1, 3, 0));
+    assert_eq!(Bump::Patch.apply(&version), Version::new(1, 2, 3));
+    let version = Version::new(1, 2, 3);
+    assert_eq!(Bump::Patch.apply(&version), Version::new(1, 2, 4));
+}
+
+#[test]
+fn apply_pre_release() {
+    let version = Version::parse("2.0.0-rc.1").unwrap();
+    assert_eq!(Bump::Major.apply(&version), Version::new(2, 0, 0));
+    assert_eq!(Bump::Minor.apply(&version), Version::new(2, 0, 0));
+    assert_eq!(Bump::Patch.apply(&version), Version::new(2, 0, 0));
+    let version = Version::parse("1.3.0-rc.1").unwrap();
+    assert_eq!(Bump::Major.apply(&version), Version::new(2, 0, 0));
+    assert_eq!(Bump::Minor.apply(&version), Version::new(1, 3, 0));
+}
+
+#[test]
+fn between() {
+    let version = Version::new(1, 2, 3);
+    let between = |new| Bump::between(&version, &Version::parse(new).unwrap());
+    assert_eq!(between("2.0.0"), Some(Bump::Major));
+    assert_eq!(between("1.3.0"), Some(Bump::Minor));
+    assert_eq!(between("1.2.4"), Some(Bump::Patch));
+    assert_eq!(between("1.2.3-rc.1"), None);
+    assert_eq!(between("1.1.9"), None);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "polars")]
+
+use metadata::{DATE, Merger, Metadata, NAME, polars::MetaDataFrame};
+use polars::prelude::*;
+
+fn frame(name: &str, date: &str, data: DataFrame) -> MetaDataFrame {
+    let meta = Metadata::from_iter([
+        (NAME.to_owned(), name.to_owned()),
+        (DATE.to_owned(), date.to_owned()),
+    ]);
+    let mut frame = MetaDataFrame::new(meta, data);
+    frame
+        .column_mut("Value")
+        .insert("Unit".to_owned(), "%".to_owned());
+    frame
+}
+
+#[test]
+fn concat() {
+    let first = frame(
+        "Sunflower",
+        "2024-09-01",
+        df! { "FattyAcid" => ["16:0"], "Value" => [6.1f64] }.unwrap(),
+    );
+    let second = frame(
+        "Safflower",
+        "2024-10-01",
+        df! { "FattyAcid" => ["18:2"], "Value" => [75.3f64] }.unwrap(),
+    );
+    let (actual, conflicts) = MetaDataFrame::concat([first, second], &Merger::new()).unwrap();
+    assert_eq!(actual.data.height(), 2);
+    assert_eq!(actual.meta[NAME], "Sunflower");
+    assert_eq!(actual.meta[DATE], "2024-10-01");
+    assert_eq!(actual.column("Value").unwrap()["Unit"], "%");
+    assert_eq!(conflicts.len(), 2);
+}
+
+#[test]
+fn join() {
+    let left = frame(
+        "Sunflower",
+        "2024-09-01",
+        df! { "FattyAcid" => ["16:0", "18:2"], "Value" => [6.1f64, 61.2] }.unwrap(),
+    );
+    let mut right = frame(
+        "Sunflower",
+        "2024-09-01",
+        df! { "FattyAcid" => ["16:0", "18:2"], "Value" => [6.3f64, 60.8] }.unwrap(),
+    );
+    right
+        .column_mut("Value")
+        .insert("Unit".to_owned(), "mol %".to_owned());
+    let (actual, conflicts) = left
+        .join(
+            right,
+            &["FattyAcid"],
+            &["FattyAcid"],
+            JoinArgs::new(JoinType::Inner),
+            &Merger::new(),
+        )
+        .unwrap();
+    assert!(conflicts.is_empty());
+    assert_eq!(actual.data.width(), 3);
+    assert_eq!(actual.column("Value").unwrap()["Unit"], "%");
+    assert_eq!(actual.column("Value_right").unwrap()["Unit"], "mol %");
+}
@@ -0,0 +1,61 @@
+use metadata::{AUTHORS, Author, Bump, DESCRIPTION, Metadata, NAME, VERSION, ValueDiff};
+
+fn metadata(entries: &[(&str, &str)]) -> Metadata {
+    entries
+        .iter()
+        .map(|&(key, value)| (key.to_owned(), value.to_owned()))
+        .collect()
+}
+
+#[test]
+fn keys() {
+    let old = metadata(&[(NAME, "Sunflower"), (DESCRIPTION, "Seed oil")]);
+    let new = metadata(&[(NAME, "Safflower"), ("Custom", "Value")]);
+    let diff = old.diff(&new);
+    assert!(!diff.is_empty());
+    assert_eq!(diff.added().collect::<Vec<_>>(), [("Custom", "Value")]);
+    assert_eq!(
+        diff.removed().collect::<Vec<_>>(),
+        [(DESCRIPTION, "Seed oil")]
+    );
+    let changed = diff.changed().collect::<Vec<_>>();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].0, NAME);
+    assert_eq!(changed[0].1.value, ValueDiff::Text);
+    assert!(old.diff(&old).is_empty());
+}
+
+#[test]
+fn authors() {
+    let old = metadata(&[(AUTHORS, "Kazakov,Sidorov,Ivanov")]);
+    let new = metadata(&[(AUTHORS, "Sidorov,Kazakov,Petrov")]);
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff.changed().next().unwrap().1.value,
+        ValueDiff::Authors {
+            inserted: vec![Author::new("Petrov")],
+            removed: vec![Author::new("Ivanov")],
+            reordered: true,
+        },
+    );
+}
+
+#[test]
+fn version() {
+    let old = metadata(&[(VERSION, "1.2.3")]);
+    let new = metadata(&[(VERSION, "1.3.0")]);
+    assert_eq!(
+        old.diff(&new).changed().next().unwrap().1.value,
+        ValueDiff::Version(Some(Bump::Minor)),
+    );
+}
+
+#[test]
+fn unified() {
+    let old = metadata(&[(NAME, "Sunflower"), (VERSION, "1.2.3")]);
+    let new = metadata(&[(NAME, "Sunflower"), (VERSION, "1.3.0")]);
+    assert_eq!(
+        old.diff(&new).to_string(),
+        "--- old\n+++ new\n@@ -1,2 +1,2 @@\n Name: Sunflower\n-Version: 1.2.3\n+Version: 1.3.0\n",
+    );
+}
@@ -0,0 +1,56 @@
+use metadata::{DATE, Format, Metadata, NAME, VERSION, VersionStyle};
+
+fn metadata(version: &str) -> Metadata {
+    Metadata::from_iter([
+        (NAME.to_owned(), "Sunflower".to_owned()),
+        (VERSION.to_owned(), version.to_owned()),
+        (DATE.to_owned(), "2024-01-01".to_owned()),
+    ])
+}
+
+#[test]
+fn display_matches_format() {
+    let metadata = metadata("1.2.0");
+    assert_eq!(metadata.to_string(), "Sunflower[1.2] 2024-01-01");
+    assert_eq!(metadata.to_string(), metadata.format(" ").to_string());
+    assert_eq!(
+        metadata.format("_").to_string(),
+        "Sunflower[1.2]_2024-01-01"
+    );
+}
+
+#[test]
+fn short_versions() {
+    for (version, expected) in [
+        ("0.10.0", "Sunflower[0.10] 2024-01-01"),
+        ("1.0.0", "Sunflower[1] 2024-01-01"),
+        ("0.0.1", "Sunflower[0.0.1] 2024-01-01"),
+        ("10.0.1", "Sunflower[10.0.1] 2024-01-01"),
+        ("1.0.0-rc.1", "Sunflower[1.0.0-rc.1] 2024-01-01"),
+        ("0.0.0", "Sunflower 2024-01-01"),
+        ("invalid", "Sunflower[invalid] 2024-01-01"),
+    ] {
+        assert_eq!(metadata(version).to_string(), expected);
+    }
+}
+
+#[test]
+fn version_styles() {
+    let metadata = metadata("1.0.0-rc.1+build.5");
+    let format = |style| {
+        let format = Format::new().with_date(false).with_version_style(style);
+        metadata.display(format).to_string()
+    };
+    assert_eq!(format(VersionStyle::Full), "Sunflower[1.0.0-rc.1+build.5]");
+    assert_eq!(format(VersionStyle::Release), "Sunflower[1.0.0-rc.1]");
+    assert_eq!(format(VersionStyle::Short), "Sunflower[1.0.0-rc.1+build.5]");
+}
+
+#[test]
+fn fields_and_date_format() {
+    let format = Format::new()
+        .with_name(false)
+        .with_version(false)
+        .with_date_format("%d.%m.%Y");
+    assert_eq!(metadata("1.0.0").display(format).to_string(), "01.01.2024");
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "egui")]
+
+use metadata::{
+    Metadata, NAME,
+    egui::{Change, History},
+};
+use std::collections::BTreeMap;
+
+fn change(key: &str, old: Option<&str>, new: Option<&str>) -> BTreeMap<String, Change> {
+    BTreeMap::from([(
+        key.to_owned(),
+        Change {
+            old: old.map(ToOwned::to_owned),
+            new: new.map(ToOwned::to_owned),
+        },
+    )])
+}
+
+#[test]
+fn merges_typing_bursts() {
+    let mut metadata = Metadata::from_iter([(NAME.to_owned(), "Sun".to_owned())]);
+    let mut history = History::default();
+    history.record(change(NAME, Some("S"), Some("Su")), 0.0);
+    history.record(change(NAME, Some("Su"), Some("Sun")), 0.5);
+    history.undo(&mut metadata);
+    assert_eq!(metadata[NAME], "S");
+    assert!(!history.can_undo());
+    history.redo(&mut metadata);
+    assert_eq!(metadata[NAME], "Sun");
+}
+
+#[test]
+fn separates_pauses_and_keys() {
+    let mut metadata = Metadata::from_iter([
+        (NAME.to_owned(), "Sun".to_owned()),
+        ("Crop".to_owned(), "Oil".to_owned()),
+    ]);
+    let mut history = History::default();
+    history.record(change(NAME, Some("Su"), Some("Sun")), 0.0);
+    history.record(change("Crop", None, Some("Oil")), 0.1);
+    history.undo(&mut metadata);
+    assert!(!metadata.contains_key("Crop"));
+    assert_eq!(metadata[NAME], "Sun");
+    history.undo(&mut metadata);
+    assert_eq!(metadata[NAME], "Su");
+    history.record(change(NAME, Some("Su"), Some("Sa")), 5.0);
+    assert!(!history.can_redo());
+}
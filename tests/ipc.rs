@@ -0,0 +1,134 @@
+#![cfg(feature = "polars")]
+
+use metadata::{AUTHORS, DATE, DESCRIPTION, Error, Metadata, NAME, VERSION, polars::MetaDataFrame};
+use polars::prelude::*;
+use std::{
+    env::temp_dir,
+    fs::remove_file,
+    io::{self, Cursor, Read},
+    path::PathBuf,
+    process,
+};
+
+/// Path in the temporary directory, unique to the process and the test
+fn temp_path(name: &str) -> PathBuf {
+    temp_dir().join(format!("metadata_{}_{name}", process::id()))
+}
+
+fn frame() -> MetaDataFrame {
+    let meta = Metadata::from_iter([
+        (NAME.to_owned(), "Sunflower".to_owned()),
+        (DESCRIPTION.to_owned(), "Seed oil, 2024 harvest".to_owned()),
+        (AUTHORS.to_owned(), "Kazakov,Sidorov".to_owned()),
+        (VERSION.to_owned(), "1.2.3-rc.1+build.5".to_owned()),
+        (DATE.to_owned(), "2024-09-01".to_owned()),
+    ]);
+    let data = df! {
+        "FattyAcid" => ["16:0", "18:1", "18:2"],
+        "Value" => [6.1f64, 28.4, 61.2],
+    }
+    .unwrap();
+    MetaDataFrame::new(meta, data)
+}
+
+#[test]
+fn round_trip() {
+    let mut expected = frame();
+    let mut bytes = Vec::new();
+    expected.write_ipc(&mut bytes).unwrap();
+    let actual = MetaDataFrame::read_ipc(Cursor::new(bytes)).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[test]
+fn round_trip_stream() {
+    let mut expected = frame();
+    let mut bytes = Vec::new();
+    expected.write_ipc_stream(&mut bytes).unwrap();
+    let actual = MetaDataFrame::read_ipc_stream(bytes.as_slice()).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[test]
+fn round_trip_open_stream() {
+    /// Source without `Seek` that never ends, like a socket left open
+    struct Open<'a>(&'a [u8]);
+
+    impl Read for Open<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.read(buf)? {
+                0 => Err(io::Error::other("read past the end of the stream")),
+                length => Ok(length),
+            }
+        }
+    }
+
+    let mut expected = frame();
+    let mut bytes = Vec::new();
+    expected.write_ipc_stream(&mut bytes).unwrap();
+    let actual = MetaDataFrame::read_ipc_stream(Open(&bytes)).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[test]
+fn round_trip_file() {
+    let path = temp_path("round_trip.arrow");
+    let mut expected = frame();
+    expected.write_ipc_file(&path).unwrap();
+    let actual = MetaDataFrame::read_ipc_file(&path).unwrap();
+    remove_file(&path).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[test]
+fn round_trip_columns() {
+    let mut expected = frame();
+    expected
+        .column_mut("Value")
+        .insert("Unit".to_owned(), "%".to_owned());
+    let mut bytes = Vec::new();
+    expected.write_ipc(&mut bytes).unwrap();
+    let actual = MetaDataFrame::read_ipc(Cursor::new(bytes)).unwrap();
+    assert_eq!(actual.columns, expected.columns);
+    let mut bytes = Vec::new();
+    expected.write_ipc_stream(&mut bytes).unwrap();
+    let actual = MetaDataFrame::read_ipc_stream(bytes.as_slice()).unwrap();
+    assert_eq!(actual.columns, expected.columns);
+}
+
+#[test]
+fn rename_and_drop_columns() {
+    let mut frame = frame();
+    frame
+        .column_mut("Value")
+        .insert("Unit".to_owned(), "%".to_owned());
+    frame.rename("Value", "Mass").unwrap();
+    assert!(frame.column("Value").is_none());
+    assert_eq!(frame.column("Mass").unwrap()["Unit"], "%");
+    frame.drop_in_place("Mass").unwrap();
+    assert!(frame.columns.is_empty());
+}
+
+#[test]
+fn footer() {
+    let mut expected = frame();
+    let mut bytes = Vec::new();
+    expected.write_ipc(&mut bytes).unwrap();
+    let actual = Metadata::from_ipc(Cursor::new(bytes)).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert_eq!(&actual.schema, &**expected.data.schema());
+    assert_eq!(actual.rows, 3);
+}
+
+#[test]
+fn unknown_format() {
+    let path = temp_path("unknown_format.csv");
+    assert!(matches!(
+        frame().write_file(&path),
+        Err(Error::Format { value, .. }) if value == "csv",
+    ));
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "polars")]
+
+use metadata::{
+    Metadata, NAME, VERSION,
+    polars::{MetaDataFrame, MetaLazyFrame},
+};
+use polars::prelude::*;
+use std::{env::temp_dir, fs::remove_file, path::PathBuf, process};
+
+/// Path in the temporary directory, unique to the process and the test
+fn temp_path(name: &str) -> PathBuf {
+    temp_dir().join(format!("metadata_{}_{name}", process::id()))
+}
+
+fn frame() -> MetaDataFrame {
+    let meta = Metadata::from_iter([
+        (NAME.to_owned(), "Sunflower".to_owned()),
+        (VERSION.to_owned(), "1.2.3".to_owned()),
+    ]);
+    let data = df! {
+        "FattyAcid" => ["16:0", "18:1", "18:2"],
+        "Value" => [6.1f64, 28.4, 61.2],
+    }
+    .unwrap();
+    let mut frame = MetaDataFrame::new(meta, data);
+    frame
+        .column_mut("Value")
+        .insert("Unit".to_owned(), "%".to_owned());
+    frame
+}
+
+#[test]
+fn scan_parquet() {
+    let path = temp_path("scan.parquet");
+    let mut expected = frame();
+    expected.write_parquet_file(&path).unwrap();
+    let mut lazy = MetaLazyFrame::scan_parquet(&path).unwrap();
+    lazy.data = lazy.data.filter(col("Value").gt(lit(10)));
+    let actual = lazy.collect().unwrap();
+    remove_file(&path).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert_eq!(actual.columns, expected.columns);
+    assert_eq!(actual.data.height(), 2);
+}
+
+#[test]
+fn scan_ipc() {
+    let path = temp_path("scan.arrow");
+    let mut expected = frame();
+    expected.write_ipc_file(&path).unwrap();
+    let actual = MetaLazyFrame::scan_ipc(&path).unwrap().collect().unwrap();
+    remove_file(&path).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert_eq!(actual.columns, expected.columns);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[test]
+fn sink_parquet() {
+    let path = temp_path("sink.parquet");
+    let expected = frame();
+    expected.clone().lazy().sink_parquet(&path).unwrap();
+    let actual = MetaDataFrame::read_parquet_file(&path).unwrap();
+    remove_file(&path).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert_eq!(actual.columns, expected.columns);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[test]
+fn drop_column() {
+    let mut lazy = frame().lazy();
+    lazy.data = lazy.data.select([col("FattyAcid")]);
+    let actual = lazy.collect().unwrap();
+    assert!(actual.columns.is_empty());
+}
+
+#[test]
+fn map_data() {
+    let expected = frame();
+    let actual = expected
+        .clone()
+        .lazy()
+        .map_data(|data| data.filter(col("Value").gt(lit(10))))
+        .collect()
+        .unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert_eq!(actual.columns, expected.columns);
+    assert_eq!(actual.data.height(), 2);
+}
+
+#[test]
+fn try_map_data() {
+    let expected = frame();
+    let actual = expected
+        .clone()
+        .try_map_data(|data| {
+            data.sort(
+                ["Value"],
+                SortMultipleOptions::default().with_order_descending(true),
+            )
+        })
+        .unwrap()
+        .record("sort by Value");
+    assert_eq!(actual.meta.name(), expected.meta.name());
+    assert_eq!(actual.meta.provenance().count(), 1);
+    assert_eq!(actual.columns, expected.columns);
+    assert_eq!(
+        actual
+            .data
+            .column("FattyAcid")
+            .unwrap()
+            .str()
+            .unwrap()
+            .get(0),
+        Some("18:2")
+    );
+}
@@ -0,0 +1,70 @@
+use metadata::{AUTHORS, DATE, Error, MergePolicy, Merger, Metadata, NAME, VERSION};
+
+fn metadata(name: &str, authors: &str, version: &str, date: &str) -> Metadata {
+    Metadata::from_iter([
+        (NAME.to_owned(), name.to_owned()),
+        (AUTHORS.to_owned(), authors.to_owned()),
+        (VERSION.to_owned(), version.to_owned()),
+        (DATE.to_owned(), date.to_owned()),
+    ])
+}
+
+#[test]
+fn built_in() {
+    let first = metadata("Sunflower", "Kazakov", "1.2.3", "2024-09-01");
+    let second = metadata("Safflower", "Sidorov,Kazakov", "1.10.0", "2024-08-01");
+    let (merged, conflicts) = Merger::new().merge([&first, &second]).unwrap();
+    assert_eq!(merged[NAME], "Sunflower");
+    assert_eq!(merged[AUTHORS], "Kazakov,Sidorov");
+    assert_eq!(merged[VERSION], "1.10.0");
+    assert_eq!(merged[DATE], "2024-09-01");
+    let keys = conflicts
+        .iter()
+        .map(|conflict| &*conflict.key)
+        .collect::<Vec<_>>();
+    assert_eq!(keys, [AUTHORS, DATE, NAME, VERSION]);
+    assert_eq!(conflicts[2].values, ["Sunflower", "Safflower"]);
+}
+
+#[test]
+fn equal_values() {
+    let first = metadata("Sunflower", "Kazakov", "1.2.3", "2024-09-01");
+    let (merged, conflicts) = Merger::new().merge([&first, &first]).unwrap();
+    assert_eq!(merged, first);
+    assert!(conflicts.is_empty());
+}
+
+#[test]
+fn policies() {
+    let first = metadata("Sunflower", "Kazakov", "1.2.3", "2024-09-01");
+    let second = metadata("Safflower", "Kazakov", "1.2.3", "2024-09-01");
+    let (merged, _) = Merger::new()
+        .with_default(MergePolicy::Last)
+        .merge([&first, &second])
+        .unwrap();
+    assert_eq!(merged[NAME], "Safflower");
+    let (merged, _) = Merger::new()
+        .with_policy(
+            NAME,
+            MergePolicy::custom(|_, values| Ok(values.join(" & "))),
+        )
+        .merge([&first, &second])
+        .unwrap();
+    assert_eq!(merged[NAME], "Sunflower & Safflower");
+    assert!(matches!(
+        Merger::new()
+            .with_default(MergePolicy::Error)
+            .merge([&first, &second]),
+        Err(Error::Conflict { key, .. }) if key == NAME,
+    ));
+}
+
+#[test]
+fn invalid_version() {
+    let first = metadata("Sunflower", "Kazakov", "1.2.3", "2024-09-01");
+    let second = metadata("Sunflower", "Kazakov", "1.x", "2024-09-01");
+    assert!(matches!(
+        Merger::new().merge([&first, &second]),
+        Err(Error::Version { value, .. }) if value == "1.x",
+    ));
+}
@@ -0,0 +1,107 @@
+use chrono::NaiveDate;
+use metadata::{AUTHORS, Author, DATE, Error, Metadata, MetadataRecord, NAME, PROVENANCE, VERSION};
+use semver::Version;
+
+#[test]
+fn accessors() {
+    let mut metadata = Metadata::default();
+    metadata.set_name("Sunflower");
+    metadata.set_authors([" Kazakov", "Sidorov "]);
+    metadata.set_version(&Version::new(0, 10, 0));
+    metadata.set_date(NaiveDate::from_ymd_opt(2024, 9, 1).unwrap());
+    assert_eq!(metadata.name(), Some("Sunflower"));
+    assert_eq!(metadata.description(), None);
+    assert_eq!(metadata[AUTHORS], "Kazakov,Sidorov");
+    assert_eq!(
+        metadata.structured_authors().unwrap(),
+        [Author::new("Kazakov"), Author::new("Sidorov")],
+    );
+    assert_eq!(metadata.authors(), ["Kazakov", "Sidorov"]);
+    assert_eq!(metadata.version().unwrap(), Version::new(0, 10, 0));
+    assert_eq!(
+        metadata.date().unwrap(),
+        NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+    );
+}
+
+#[test]
+fn accessor_errors() {
+    let mut metadata = Metadata::default();
+    assert!(matches!(metadata.date(), Err(Error::Missing { key }) if key == "Date"));
+    metadata.insert(VERSION.to_owned(), "1.x".to_owned());
+    assert!(matches!(metadata.version(), Err(Error::Version { value, .. }) if value == "1.x"));
+}
+
+#[test]
+fn record_round_trip() {
+    let metadata = Metadata::from_iter([
+        (NAME.to_owned(), "Sunflower".to_owned()),
+        (AUTHORS.to_owned(), "Kazakov,Sidorov".to_owned()),
+        (VERSION.to_owned(), "1.2.3-rc.1".to_owned()),
+        (DATE.to_owned(), "2024-09-01".to_owned()),
+        ("Source".to_owned(), "Field trial".to_owned()),
+    ]);
+    let record = MetadataRecord::try_from(&metadata).unwrap();
+    assert_eq!(record.name.as_deref(), Some("Sunflower"));
+    assert_eq!(record.description, None);
+    assert_eq!(record.version, Some(Version::parse("1.2.3-rc.1").unwrap()));
+    assert_eq!(record.extra["Source"], "Field trial");
+    assert_eq!(Metadata::from(record), metadata);
+}
+
+#[test]
+fn record_round_trip_non_canonical() {
+    for (key, value) in [
+        (AUTHORS, "Kazakov, Sidorov"),
+        (AUTHORS, ""),
+        (DATE, "2024-9-1"),
+    ] {
+        let metadata = Metadata::from_iter([(key.to_owned(), value.to_owned())]);
+        let record = MetadataRecord::try_from(&metadata).unwrap();
+        assert!(record.extra.is_empty());
+        assert_eq!(Metadata::from(record), metadata);
+    }
+}
+
+#[test]
+fn record_edit_non_canonical() {
+    let metadata = Metadata::from_iter([
+        (AUTHORS.to_owned(), "Kazakov, Sidorov".to_owned()),
+        (VERSION.to_owned(), "1.2.3".to_owned()),
+        (DATE.to_owned(), "2024-9-1".to_owned()),
+    ]);
+    let mut record = MetadataRecord::try_from(&metadata).unwrap();
+    record.authors.push(Author::new("Petrov"));
+    record.date = NaiveDate::from_ymd_opt(2024, 9, 2);
+    let edited = Metadata::from(record.clone());
+    assert_eq!(edited[AUTHORS], "Kazakov,Sidorov,Petrov");
+    assert_eq!(edited[DATE], "2024-09-02");
+    record.authors.clear();
+    record.date = None;
+    let cleared = Metadata::from(record);
+    assert_eq!(cleared.get(AUTHORS), None);
+    assert_eq!(cleared.get(DATE), None);
+    assert_eq!(cleared[VERSION], "1.2.3");
+}
+
+#[test]
+fn record() {
+    let mut metadata = Metadata::default();
+    assert_eq!(metadata.provenance().count(), 0);
+    let date = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+    metadata.record_at("filter", date);
+    metadata.record_at("sort\nby Value", date.succ_opt().unwrap());
+    assert_eq!(metadata.date().unwrap(), date.succ_opt().unwrap());
+    assert_eq!(
+        metadata.provenance().collect::<Vec<_>>(),
+        ["2024-09-01 filter", "2024-09-02 sort by Value"],
+    );
+    assert_eq!(metadata[PROVENANCE].lines().count(), 2);
+    // Today's date, read back in case the day changed in between
+    metadata.record("select");
+    let date = metadata.date().unwrap();
+    assert_eq!(
+        metadata.provenance().last(),
+        Some(&*format!("{date} select")),
+    );
+}
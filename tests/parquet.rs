@@ -0,0 +1,179 @@
+#![cfg(feature = "polars")]
+
+use metadata::{AUTHORS, DATE, DESCRIPTION, Metadata, NAME, VERSION, polars::MetaDataFrame};
+use polars::prelude::*;
+use std::{
+    env::temp_dir,
+    fs::{File, remove_file},
+    io::Cursor,
+    path::PathBuf,
+    process,
+};
+
+/// Path in the temporary directory, unique to the process and the test
+fn temp_path(name: &str) -> PathBuf {
+    temp_dir().join(format!("metadata_{}_{name}", process::id()))
+}
+
+fn frame() -> MetaDataFrame {
+    let meta = Metadata::from_iter([
+        (NAME.to_owned(), "Sunflower".to_owned()),
+        (DESCRIPTION.to_owned(), "Seed oil, 2024 harvest".to_owned()),
+        (AUTHORS.to_owned(), "Kazakov,Sidorov".to_owned()),
+        (VERSION.to_owned(), "1.2.3-rc.1+build.5".to_owned()),
+        (DATE.to_owned(), "2024-09-01".to_owned()),
+        ("Custom".to_owned(), String::new()),
+    ]);
+    let data = df! {
+        "FattyAcid" => ["16:0", "18:1", "18:2"],
+        "Value" => [6.1f64, 28.4, 61.2],
+    }
+    .unwrap();
+    MetaDataFrame::new(meta, data)
+}
+
+#[test]
+fn round_trip() {
+    let mut expected = frame();
+    let mut bytes = Vec::new();
+    expected.write_parquet(&mut bytes).unwrap();
+    let actual = MetaDataFrame::read_parquet(Cursor::new(bytes)).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[test]
+fn round_trip_file() {
+    let path = temp_path("round_trip.parquet");
+    let mut expected = frame();
+    expected.write_parquet_file(&path).unwrap();
+    let actual = MetaDataFrame::read_parquet_file(&path).unwrap();
+    remove_file(&path).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[test]
+fn round_trip_columns() {
+    let mut expected = frame();
+    expected
+        .column_mut("Value")
+        .insert("Unit".to_owned(), "%".to_owned());
+    let mut bytes = Vec::new();
+    expected.write_parquet(&mut bytes).unwrap();
+    let actual = MetaDataFrame::read_parquet(Cursor::new(bytes)).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert_eq!(actual.columns, expected.columns);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[test]
+fn round_trip_skips_arrow_schema() {
+    let mut expected = frame();
+    expected
+        .column_mut("Value")
+        .insert("Unit".to_owned(), "%".to_owned());
+    let mut written = expected.clone();
+    written
+        .meta
+        .insert("ARROW:schema".to_owned(), "invalid".to_owned());
+    let mut bytes = Vec::new();
+    written.write_parquet(&mut bytes).unwrap();
+    let actual = MetaDataFrame::read_parquet(Cursor::new(bytes)).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert_eq!(actual.columns, expected.columns);
+}
+
+#[test]
+fn footer() {
+    let mut expected = frame();
+    let mut bytes = Vec::new();
+    expected.write_parquet(&mut bytes).unwrap();
+    let actual = Metadata::from_parquet(Cursor::new(bytes)).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert_eq!(&actual.schema, &**expected.data.schema());
+    assert_eq!(actual.rows, 3);
+}
+
+#[test]
+fn write_footer() {
+    let path = temp_path("write_footer.parquet");
+    let mut expected = frame();
+    expected.write_parquet_file(&path).unwrap();
+    expected.meta.set_name("Safflower");
+    expected.meta.remove("Custom");
+    expected.meta.write_parquet_footer(&path).unwrap();
+    let actual = MetaDataFrame::read_parquet_file(&path).unwrap();
+    remove_file(&path).unwrap();
+    assert_eq!(actual.meta, expected.meta);
+    assert!(actual.data.equals(&expected.data));
+}
+
+#[cfg(unix)]
+#[test]
+fn write_footer_keeps_permissions() {
+    use std::{
+        fs::{create_dir_all, metadata, read_dir, remove_dir_all, set_permissions},
+        os::unix::fs::PermissionsExt,
+    };
+
+    let directory = temp_path("write_footer_permissions");
+    create_dir_all(&directory).unwrap();
+    let path = directory.join("frame.parquet");
+    let mut expected = frame();
+    expected.write_parquet_file(&path).unwrap();
+    set_permissions(&path, PermissionsExt::from_mode(0o640)).unwrap();
+    expected.meta.write_parquet_footer(&path).unwrap();
+    let mode = metadata(&path).unwrap().permissions().mode();
+    let files = read_dir(&directory).unwrap().count();
+    remove_dir_all(&directory).unwrap();
+    assert_eq!(mode & 0o777, 0o640);
+    assert_eq!(files, 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn write_footer_through_symlink() {
+    use std::{
+        fs::{create_dir_all, remove_dir_all, symlink_metadata},
+        os::unix::fs::symlink,
+    };
+
+    let directory = temp_path("write_footer_symlink");
+    create_dir_all(&directory).unwrap();
+    let path = directory.join("frame.parquet");
+    let link = directory.join("link.parquet");
+    let mut expected = frame();
+    expected.write_parquet_file(&path).unwrap();
+    symlink(&path, &link).unwrap();
+    expected.meta.set_name("Safflower");
+    expected.meta.write_parquet_footer(&link).unwrap();
+    let is_symlink = symlink_metadata(&link).unwrap().is_symlink();
+    let actual = MetaDataFrame::read_parquet_file(&path).unwrap();
+    remove_dir_all(&directory).unwrap();
+    assert!(is_symlink);
+    assert_eq!(actual.meta, expected.meta);
+}
+
+#[test]
+fn write_footer_skips_arrow_schema() {
+    let path = temp_path("write_footer_arrow_schema.parquet");
+    let mut expected = frame();
+    expected.write_parquet_file(&path).unwrap();
+    let mut meta = expected.meta.clone();
+    meta.insert("ARROW:schema".to_owned(), "invalid".to_owned());
+    meta.write_parquet_footer(&path).unwrap();
+    let schemas = ParquetReader::new(File::open(&path).unwrap())
+        .get_metadata()
+        .unwrap()
+        .key_value_metadata()
+        .iter()
+        .flatten()
+        .filter(|key_value| key_value.key == "ARROW:schema")
+        .count();
+    let actual = MetaDataFrame::read_parquet_file(&path).unwrap();
+    remove_file(&path).unwrap();
+    assert_eq!(schemas, 1);
+    assert_eq!(actual.meta, expected.meta);
+    assert!(actual.data.equals(&expected.data));
+}
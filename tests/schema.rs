@@ -0,0 +1,59 @@
+#![cfg(all(feature = "json", feature = "toml"))]
+
+use metadata::{Metadata, Schema, Severity, ValueType};
+
+const TOML: &str = r#"
+[[fields]]
+key = "Crop"
+required = true
+description = "Crop species"
+
+[[fields]]
+key = "Replicates"
+type = "number"
+default = "3"
+
+[[fields]]
+key = "Source"
+type = "url"
+"#;
+
+const JSON: &str = r#"{
+    "fields": [
+        { "key": "Crop", "required": true, "description": "Crop species" },
+        { "key": "Replicates", "type": "number", "default": "3" },
+        { "key": "Source", "type": "url" }
+    ]
+}"#;
+
+#[test]
+fn load() {
+    let schema = Schema::from_toml(TOML).unwrap();
+    assert_eq!(schema, Schema::from_json(JSON).unwrap());
+    assert_eq!(schema.fields.len(), 3);
+    assert_eq!(schema.fields[0].kind, ValueType::String);
+    assert_eq!(schema.field("Replicates").unwrap().kind, ValueType::Number);
+    assert_eq!(schema.field("Source").unwrap().kind, ValueType::Url);
+}
+
+#[test]
+fn validate() {
+    let schema = Schema::from_toml(TOML).unwrap();
+    let mut metadata = Metadata::from_iter([("Source".to_owned(), "field trial".to_owned())]);
+    let diagnostics = schema.validate(&metadata);
+    let keys = diagnostics
+        .iter()
+        .map(|diagnostic| (&*diagnostic.key, diagnostic.severity))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        [("Crop", Severity::Error), ("Source", Severity::Error)]
+    );
+    metadata.insert("Crop".to_owned(), "Sunflower".to_owned());
+    metadata.insert("Source".to_owned(), "https://example.org/trial".to_owned());
+    schema.apply_defaults(&mut metadata);
+    assert_eq!(metadata["Replicates"], "3");
+    assert!(schema.validate(&metadata).is_empty());
+    metadata.insert("Replicates".to_owned(), "three".to_owned());
+    assert_eq!(schema.validate(&metadata).len(), 1);
+}
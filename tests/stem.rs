@@ -0,0 +1,85 @@
+use metadata::{DATE, FILE_STEM_LENGTH, Metadata, NAME, VERSION};
+
+#[test]
+fn file_stem() {
+    let metadata = Metadata::from_iter([
+        (
+            NAME.to_owned(),
+            r#"Sunflower: "Seed" oil / 2024_harvest?"#.to_owned(),
+        ),
+        (VERSION.to_owned(), "1.2.3-rc.1".to_owned()),
+        (DATE.to_owned(), "2024-09-01".to_owned()),
+    ]);
+    let stem = metadata.file_stem();
+    assert_eq!(
+        stem,
+        "sunflower-seed-oil-2024-harvest_v1.2.3-rc.1_2024-09-01"
+    );
+    let parsed = Metadata::from_file_stem(&stem);
+    assert_eq!(parsed.name(), Some("sunflower-seed-oil-2024-harvest"));
+    assert_eq!(parsed[VERSION], "1.2.3-rc.1");
+    assert_eq!(parsed[DATE], "2024-09-01");
+    assert_eq!(parsed.file_stem(), stem);
+}
+
+#[test]
+fn file_stem_skips_missing_and_default() {
+    let metadata = Metadata::from_iter([
+        (VERSION.to_owned(), "0.0.0".to_owned()),
+        (DATE.to_owned(), "2024-09-01".to_owned()),
+    ]);
+    assert_eq!(metadata.file_stem(), "untitled_2024-09-01");
+    assert_eq!(
+        Metadata::from_file_stem("untitled_2024-09-01")[DATE],
+        "2024-09-01"
+    );
+    assert_eq!(Metadata::default().file_stem(), "untitled");
+    assert_eq!(Metadata::from_file_stem("untitled"), Metadata::default());
+}
+
+#[test]
+fn file_stem_name_like_date() {
+    let metadata = Metadata::from_iter([(NAME.to_owned(), "2024-09-01".to_owned())]);
+    let stem = metadata.file_stem();
+    assert_eq!(stem, "2024-09-01");
+    assert_eq!(Metadata::from_file_stem(&stem), metadata);
+}
+
+#[test]
+fn file_stem_reserved() {
+    let metadata = Metadata::from_iter([(NAME.to_owned(), "CON".to_owned())]);
+    assert_eq!(metadata.file_stem(), "con-file");
+    let metadata = Metadata::from_iter([(NAME.to_owned(), "lpt1".to_owned())]);
+    assert_eq!(metadata.file_stem(), "lpt1-file");
+}
+
+#[test]
+fn file_stem_length() {
+    let metadata = Metadata::from_iter([
+        (NAME.to_owned(), "Подсолнечник ".repeat(20)),
+        (DATE.to_owned(), "2024-09-01".to_owned()),
+    ]);
+    let stem = metadata.file_stem();
+    assert!(stem.len() <= FILE_STEM_LENGTH);
+    assert!(stem.starts_with("подсолнечник-подсолнечник"));
+    assert!(stem.ends_with("_2024-09-01"));
+}
+
+#[test]
+fn file_stem_long_version() {
+    let metadata = Metadata::from_iter([
+        (NAME.to_owned(), "Sunflower".to_owned()),
+        (VERSION.to_owned(), format!("1.2.3-{}", "rc".repeat(60))),
+        (DATE.to_owned(), "2024-09-01".to_owned()),
+    ]);
+    assert_eq!(metadata.file_stem(), "sunflower_2024-09-01");
+}
+
+#[test]
+fn file_name() {
+    let metadata = Metadata::from_iter([
+        (NAME.to_owned(), "Sunflower".to_owned()),
+        (VERSION.to_owned(), "1.2.3".to_owned()),
+    ]);
+    assert_eq!(metadata.file_name("parquet"), "sunflower_v1.2.3.parquet");
+}
@@ -0,0 +1,74 @@
+use metadata::{DATE, Metadata, NAME, Rule, Severity, VERSION, Validator};
+
+#[test]
+fn built_in_rules() {
+    let mut metadata = Metadata::from_iter([
+        (NAME.to_owned(), " ".to_owned()),
+        (VERSION.to_owned(), "1.0".to_owned()),
+        (DATE.to_owned(), "01.09.2024".to_owned()),
+    ]);
+    let validator = Validator::new();
+    let keys = validator
+        .validate(&metadata)
+        .into_iter()
+        .map(|diagnostic| diagnostic.key)
+        .collect::<Vec<_>>();
+    assert_eq!(keys, [NAME, VERSION, DATE]);
+    metadata.insert(NAME.to_owned(), "Sunflower".to_owned());
+    metadata.insert(VERSION.to_owned(), "1.0.0".to_owned());
+    metadata.insert(DATE.to_owned(), "2024-09-01".to_owned());
+    assert!(validator.validate(&metadata).is_empty());
+    assert_eq!(validator.validate(&Metadata::default()).len(), 1);
+}
+
+#[test]
+fn user_rules() {
+    let metadata = Metadata::from_iter([
+        ("Crop".to_owned(), "Wheat".to_owned()),
+        ("Replicates".to_owned(), "two".to_owned()),
+    ]);
+    let diagnostics = Validator::empty()
+        .with_rule(
+            "Crop",
+            Rule::allowed(["Sunflower", "Rapeseed"]),
+            Severity::Error,
+        )
+        .with_rule(
+            "Replicates",
+            Rule::custom(|value| {
+                value
+                    .parse::<u8>()
+                    .map(drop)
+                    .map_err(|error| error.to_string())
+            }),
+            Severity::Warning,
+        )
+        .with_rule("Source", Rule::Required, Severity::Info)
+        .validate(&metadata);
+    let actual = diagnostics
+        .iter()
+        .map(|diagnostic| (&*diagnostic.key, diagnostic.severity))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actual,
+        [
+            ("Crop", Severity::Error),
+            ("Replicates", Severity::Warning),
+            ("Source", Severity::Info),
+        ],
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex_rule() {
+    let validator = Validator::empty().with_rule(
+        "Plot",
+        Rule::regex(r"^[A-Z]-\d+$").unwrap(),
+        Severity::Error,
+    );
+    let mut metadata = Metadata::from_iter([("Plot".to_owned(), "A-12".to_owned())]);
+    assert!(validator.validate(&metadata).is_empty());
+    metadata.insert("Plot".to_owned(), "12".to_owned());
+    assert_eq!(validator.validate(&metadata).len(), 1);
+}